@@ -1,9 +1,180 @@
 use std::f32::consts::PI;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign};
 
+use num_traits::{Float, FloatConst};
+
+#[deprecated(note = "use `Rad::from(Deg(angle))` instead")]
 pub fn degrees_to_radians(angle: f32) -> f32 {
     angle * (PI / 180.0)
 }
 
+#[deprecated(note = "use `Deg::from(Rad(angle))` instead")]
 pub fn radians_to_degrees(angle: f32) -> f32 {
     angle / (PI / 180.0)
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+/// An angle in radians.
+pub struct Rad<T: Float>(pub T);
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+/// An angle in degrees.
+pub struct Deg<T: Float>(pub T);
+
+/// Implements the methods and operators shared by `Rad` and `Deg`.
+///
+/// `$full_turn` is an expression for the size of a full turn in the unit of the type.
+macro_rules! impl_angle {
+    ($angle:ident, $full_turn:expr) => {
+        impl<T> $angle<T>
+            where T: Float + FloatConst
+        {
+            /// Returns the size of a full turn
+            pub fn full_turn() -> Self {
+                $angle($full_turn)
+            }
+            /// Returns the angle wrapped to `[0, full turn)`
+            pub fn normalize(self) -> Self {
+                let full_turn = Self::full_turn().0;
+                let wrapped = self.0 % full_turn;
+                if wrapped < T::zero() {
+                    // Adding a full turn to a tiny negative value can round to a full turn
+                    let result = wrapped + full_turn;
+                    $angle(if result < full_turn { result } else { T::zero() })
+                }
+                else {
+                    $angle(wrapped)
+                }
+            }
+            /// Returns the angle wrapped to `(-half turn, half turn]`
+            pub fn normalize_signed(self) -> Self {
+                let full_turn = Self::full_turn().0;
+                let half_turn = full_turn / (T::one() + T::one());
+                let normalized = self.normalize().0;
+                if normalized > half_turn {
+                    $angle(normalized - full_turn)
+                }
+                else {
+                    $angle(normalized)
+                }
+            }
+        }
+        impl<T> $angle<T>
+            where T: Float
+        {
+            pub fn sin(self) -> T {
+                Rad::from(self).0.sin()
+            }
+            pub fn cos(self) -> T {
+                Rad::from(self).0.cos()
+            }
+            pub fn tan(self) -> T {
+                Rad::from(self).0.tan()
+            }
+            /// Returns the sine and cosine of the angle
+            pub fn sin_cos(self) -> (T, T) {
+                Rad::from(self).0.sin_cos()
+            }
+            pub fn asin(value: T) -> Self {
+                Rad(value.asin()).into()
+            }
+            pub fn acos(value: T) -> Self {
+                Rad(value.acos()).into()
+            }
+            pub fn atan(value: T) -> Self {
+                Rad(value.atan()).into()
+            }
+            pub fn atan2(y: T, x: T) -> Self {
+                Rad(y.atan2(x)).into()
+            }
+        }
+
+        impl<T> Add for $angle<T>
+            where T: Float
+        {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $angle(self.0 + other.0)
+            }
+        }
+        impl<T> Sub for $angle<T>
+            where T: Float
+        {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $angle(self.0 - other.0)
+            }
+        }
+        impl<T> Neg for $angle<T>
+            where T: Float
+        {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $angle(-self.0)
+            }
+        }
+        impl<T> Mul<T> for $angle<T>
+            where T: Float
+        {
+            type Output = Self;
+
+            fn mul(self, other: T) -> Self {
+                $angle(self.0 * other)
+            }
+        }
+        impl<T> Div<T> for $angle<T>
+            where T: Float
+        {
+            type Output = Self;
+
+            fn div(self, other: T) -> Self {
+                $angle(self.0 / other)
+            }
+        }
+        impl<T> Div for $angle<T>
+            where T: Float
+        {
+            type Output = T;
+
+            /// Returns the ratio between the two angles
+            fn div(self, other: Self) -> T {
+                self.0 / other.0
+            }
+        }
+        impl<T> AddAssign for $angle<T>
+            where T: Float
+        {
+            fn add_assign(&mut self, other: Self) {
+                self.0 = self.0 + other.0;
+            }
+        }
+        impl<T> SubAssign for $angle<T>
+            where T: Float
+        {
+            fn sub_assign(&mut self, other: Self) {
+                self.0 = self.0 - other.0;
+            }
+        }
+    };
+}
+
+impl_angle!(Rad, T::TAU());
+impl_angle!(Deg, T::from(360.0).unwrap());
+
+impl<T> From<Deg<T>> for Rad<T>
+    where T: Float
+{
+    fn from(angle: Deg<T>) -> Self {
+        Rad(angle.0.to_radians())
+    }
+}
+impl<T> From<Rad<T>> for Deg<T>
+    where T: Float
+{
+    fn from(angle: Rad<T>) -> Self {
+        Deg(angle.0.to_degrees())
+    }
+}
@@ -0,0 +1,193 @@
+use crate::error::{ShapeError, SolveError};
+use crate::matrix::{DMatrix, MatrixMN};
+use crate::vector::{DVector, VectorN};
+
+use num_traits::Float;
+use std::convert::TryFrom;
+
+use super::{Cholesky, Ldl};
+
+impl<T> Cholesky<T>
+    where T: Float
+{
+    /// Decomposes a symmetric positive-definite matrix
+    ///
+    /// Only the lower triangle is read, the upper one is assumed to mirror it.
+    /// Returns `None` if the matrix is not square or not positive-definite.
+    pub fn new(matrix: &DMatrix<T>) -> Option<Self> {
+        let size = matrix.rows();
+        if matrix.columns() != size {
+            return None;
+        }
+        let mut l = DMatrix::zeros(size, size);
+
+        for j in 0..size {
+            let diagonal = matrix[[j, j]] - (0..j).fold(T::zero(), |sum, k| sum + l[[j, k]] * l[[j, k]]);
+            if diagonal <= T::zero() || diagonal.is_nan() {
+                return None;
+            }
+            let diagonal = diagonal.sqrt();
+            l[[j, j]] = diagonal;
+            for i in (j + 1)..size {
+                let sum = (0..j).fold(T::zero(), |sum, k| sum + l[[i, k]] * l[[j, k]]);
+                l[[i, j]] = (matrix[[i, j]] - sum) / diagonal;
+            }
+        }
+        Some(Self { l })
+    }
+    /// Returns the lower triangular factor `L`, where `A = L * Lᵀ`
+    pub fn l(&self) -> &DMatrix<T> {
+        &self.l
+    }
+    /// Returns the determinant of the decomposed matrix
+    pub fn determinant(&self) -> T {
+        let size = self.l.rows();
+        let product = (0..size).fold(T::one(), |product, i| product * self.l[[i, i]]);
+        product * product
+    }
+    /// Solves `A * x = b` for `x` by forward and back substitution
+    pub fn solve(&self, b: &DVector<T>) -> Result<DVector<T>, SolveError> {
+        let size = self.l.rows();
+        if b.values.len() != size {
+            return Err(ShapeError::new([size, 1], [b.values.len(), 1]).into());
+        }
+        let mut x = b.values.clone();
+        // L * y = b
+        for i in 0..size {
+            for j in 0..i {
+                x[i] = x[i] - self.l[[i, j]] * x[j];
+            }
+            x[i] = x[i] / self.l[[i, i]];
+        }
+        // Lᵀ * x = y
+        for i in (0..size).rev() {
+            for j in (i + 1)..size {
+                x[i] = x[i] - self.l[[j, i]] * x[j];
+            }
+            x[i] = x[i] / self.l[[i, i]];
+        }
+        Ok(DVector::new(x))
+    }
+    /// Solves `A * x = b` for a fixed-size `b`
+    ///
+    /// Works with both `VectorN` and the vector type of the same size, like `Vector3` for a 3x3 matrix
+    pub fn solve_vector<V, const N: usize>(&self, b: V) -> Result<V, SolveError>
+        where V: Into<VectorN<T, N>> + From<VectorN<T, N>>
+    {
+        let x = self.solve(&DVector::from(b.into()))?;
+        Ok(V::from(VectorN::try_from(x)?))
+    }
+}
+
+impl<T> Ldl<T>
+    where T: Float
+{
+    /// Decomposes a symmetric positive semi-definite matrix without square roots
+    ///
+    /// Only the lower triangle is read, the upper one is assumed to mirror it.
+    /// Pivots at or below the largest diagonal element times the machine epsilon and the size count as zero.
+    /// Returns `None` if the matrix is not square or not positive semi-definite.
+    pub fn new(matrix: &DMatrix<T>) -> Option<Self> {
+        let size = matrix.rows();
+        if matrix.columns() != size {
+            return None;
+        }
+        let largest = (0..size).fold(T::zero(), |largest, i| largest.max(matrix[[i, i]].abs()));
+        let tolerance = largest * T::epsilon() * T::from(size).unwrap();
+        let mut l = DMatrix::identity(size);
+        let mut d = DVector::zeros(size);
+
+        for j in 0..size {
+            let pivot = matrix[[j, j]] - (0..j).fold(T::zero(), |sum, k| sum + l[[j, k]] * l[[j, k]] * d[k]);
+            if pivot < -tolerance || pivot.is_nan() {
+                return None;
+            }
+            for i in (j + 1)..size {
+                let value = matrix[[i, j]] - (0..j).fold(T::zero(), |sum, k| sum + l[[i, k]] * l[[j, k]] * d[k]);
+                if pivot > tolerance {
+                    l[[i, j]] = value / pivot;
+                }
+                else if value.abs() > tolerance {
+                    // A zero pivot with a non-zero column below it means the matrix is indefinite
+                    return None;
+                }
+            }
+            d[j] = if pivot > tolerance { pivot } else { T::zero() };
+        }
+        Some(Self { l, d })
+    }
+    /// Returns the unit lower triangular factor `L`, where `A = L * D * Lᵀ`
+    pub fn l(&self) -> &DMatrix<T> {
+        &self.l
+    }
+    /// Returns the diagonal of `D`
+    pub fn d(&self) -> &DVector<T> {
+        &self.d
+    }
+    /// Returns the determinant of the decomposed matrix
+    pub fn determinant(&self) -> T {
+        self.d.values.iter().fold(T::one(), |product, value| product * *value)
+    }
+    /// Solves `A * x = b` for `x` by forward and back substitution
+    ///
+    /// Fails with the first zero pivot for singular matrices
+    pub fn solve(&self, b: &DVector<T>) -> Result<DVector<T>, SolveError> {
+        let size = self.l.rows();
+        if b.values.len() != size {
+            return Err(ShapeError::new([size, 1], [b.values.len(), 1]).into());
+        }
+        if let Some(column) = self.d.values.iter().position(|value| *value == T::zero()) {
+            return Err(SolveError::Singular(column));
+        }
+        let mut x = b.values.clone();
+        // L * y = b
+        for i in 0..size {
+            for j in 0..i {
+                x[i] = x[i] - self.l[[i, j]] * x[j];
+            }
+        }
+        // D * Lᵀ * x = y
+        for i in (0..size).rev() {
+            x[i] = x[i] / self.d[i];
+            for j in (i + 1)..size {
+                x[i] = x[i] - self.l[[j, i]] * x[j];
+            }
+        }
+        Ok(DVector::new(x))
+    }
+    /// Solves `A * x = b` for a fixed-size `b`
+    ///
+    /// Works with both `VectorN` and the vector type of the same size, like `Vector3` for a 3x3 matrix
+    pub fn solve_vector<V, const N: usize>(&self, b: V) -> Result<V, SolveError>
+        where V: Into<VectorN<T, N>> + From<VectorN<T, N>>
+    {
+        let x = self.solve(&DVector::from(b.into()))?;
+        Ok(V::from(VectorN::try_from(x)?))
+    }
+}
+
+impl<T> DMatrix<T>
+    where T: Float
+{
+    /// Returns the Cholesky decomposition of a symmetric positive-definite matrix
+    pub fn cholesky(&self) -> Option<Cholesky<T>> {
+        Cholesky::new(self)
+    }
+    /// Returns the LDLᵀ decomposition of a symmetric positive semi-definite matrix
+    pub fn ldl(&self) -> Option<Ldl<T>> {
+        Ldl::new(self)
+    }
+}
+
+impl<T, const N: usize> MatrixMN<T, N, N>
+    where T: Float
+{
+    /// Returns the Cholesky decomposition of a symmetric positive-definite matrix
+    pub fn cholesky(&self) -> Option<Cholesky<T>> {
+        Cholesky::new(&DMatrix::from(*self))
+    }
+    /// Returns the LDLᵀ decomposition of a symmetric positive semi-definite matrix
+    pub fn ldl(&self) -> Option<Ldl<T>> {
+        Ldl::new(&DMatrix::from(*self))
+    }
+}
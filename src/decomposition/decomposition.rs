@@ -0,0 +1,86 @@
+mod cholesky;
+mod eigen;
+mod lu;
+mod qr;
+mod svd;
+
+pub use qr::{gram_schmidt, least_squares};
+
+use crate::matrix::DMatrix;
+use crate::vector::{DVector, Vector3};
+
+use num_traits::Float;
+
+#[derive(Debug, Clone, PartialEq)]
+/// An LU decomposition with partial pivoting, `P * A = L * U`.
+///
+/// `L` is lower triangular with ones on the diagonal and `U` is upper triangular.
+/// Both are stored in a single square matrix, `L` below and `U` on and above the diagonal.
+pub struct Lu<T: Float> {
+    size: usize,
+    values: Vec<T>,
+    permutation: Vec<usize>,
+    odd_swaps: bool,
+    singular_pivot: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A QR decomposition, `A = Q * R`.
+///
+/// `Q` has orthonormal columns and `R` is upper triangular.
+/// For an m x n matrix `Q` is m x min(m, n) and `R` is min(m, n) x n.
+pub struct Qr<T: Float> {
+    q: DMatrix<T>,
+    r: DMatrix<T>,
+    tolerance: T,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// The eigenvalues and eigenvectors of a symmetric 3x3 matrix.
+///
+/// The eigenvalues are sorted from largest to smallest, `eigenvectors[i]` belongs to `eigenvalues[i]`.
+/// The eigenvectors are unit length and orthogonal to each other.
+pub struct SymmetricEigen<T: Float> {
+    pub eigenvalues: [T; 3],
+    pub eigenvectors: [Vector3<T>; 3],
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A singular value decomposition, `A = U * Σ * Vᵀ`.
+///
+/// For an m x n matrix with k = min(m, n), `U` is m x k with orthonormal columns,
+/// `Σ` holds the k singular values from largest to smallest and `Vᵀ` is k x n with orthonormal rows.
+pub struct Svd<T: Float> {
+    u: DMatrix<T>,
+    singular_values: DVector<T>,
+    v_t: DMatrix<T>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A Cholesky decomposition of a symmetric positive-definite matrix, `A = L * Lᵀ`.
+///
+/// `L` is lower triangular with a positive diagonal.
+pub struct Cholesky<T: Float> {
+    l: DMatrix<T>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// An LDLᵀ decomposition of a symmetric positive semi-definite matrix, `A = L * D * Lᵀ`.
+///
+/// `L` is lower triangular with ones on the diagonal and `D` is diagonal with non-negative values.
+pub struct Ldl<T: Float> {
+    l: DMatrix<T>,
+    d: DVector<T>,
+}
+
+fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b.iter()).fold(T::zero(), |sum, (a, b)| sum + *a * *b)
+}
+
+fn norm<T: Float>(values: &[T]) -> T {
+    dot(values, values).sqrt()
+}
+
+fn unit<T: Float>(len: usize, axis: usize) -> Vec<T> {
+    (0..len).map(|i| if i == axis { T::one() } else { T::zero() }).collect()
+}
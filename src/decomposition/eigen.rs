@@ -0,0 +1,93 @@
+use crate::matrix::Matrix3;
+
+use num_traits::Float;
+use std::cmp::Ordering;
+
+use super::SymmetricEigen;
+
+/// The number of sweeps `symmetric_eigen` does at most
+const DEFAULT_MAX_SWEEPS: usize = 50;
+
+impl<T> Matrix3<T>
+    where T: Float
+{
+    /// Returns the eigenvalues and eigenvectors of a symmetric matrix
+    ///
+    /// Only the upper triangle is read, the lower one is assumed to mirror it.
+    /// Iterates until the off-diagonal elements are negligible compared to the machine epsilon.
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T> {
+        self.symmetric_eigen_with(T::epsilon(), DEFAULT_MAX_SWEEPS).0
+    }
+    /// Returns the eigenvalues and eigenvectors of a symmetric matrix using the cyclic Jacobi method
+    ///
+    /// tolerance: the sweeps stop once the norm of the off-diagonal elements is at most
+    /// `tolerance` times the norm of the matrix
+    ///
+    /// max_sweeps: the maximum number of sweeps over the off-diagonal elements
+    ///
+    /// The second value is `false` if the tolerance was not reached within `max_sweeps`,
+    /// the result then holds the last approximation.
+    pub fn symmetric_eigen_with(&self, tolerance: T, max_sweeps: usize) -> (SymmetricEigen<T>, bool) {
+        let mut a = self.values;
+        a[1][0] = a[0][1];
+        a[2][0] = a[0][2];
+        a[2][1] = a[1][2];
+        let mut v = Matrix3::identity().values;
+
+        let norm = a.iter().flatten().fold(T::zero(), |sum, value| sum + *value * *value).sqrt();
+        let mut converged = false;
+
+        for _ in 0..max_sweeps {
+            if off_diagonal_norm(&a) <= tolerance * norm {
+                converged = true;
+                break;
+            }
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == T::zero() {
+                    continue;
+                }
+                let (c, s) = jacobi_rotation(a[p][p], a[q][q], a[p][q]);
+
+                // A = Jᵀ * A * J and V = V * J, where J rotates in the p-q plane
+                for row in a.iter_mut().chain(v.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                for (k, (&x, &y)) in row_p.iter().zip(row_q.iter()).enumerate() {
+                    a[p][k] = c * x - s * y;
+                    a[q][k] = s * x + c * y;
+                }
+                a[p][q] = T::zero();
+                a[q][p] = T::zero();
+            }
+        }
+        if !converged {
+            converged = off_diagonal_norm(&a) <= tolerance * norm;
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| a[j][j].partial_cmp(&a[i][i]).unwrap_or(Ordering::Equal));
+
+        let vectors = Matrix3::new(v).as_vectors_collumns();
+        let eigen = SymmetricEigen {
+            eigenvalues: [a[order[0]][order[0]], a[order[1]][order[1]], a[order[2]][order[2]]],
+            eigenvectors: [vectors[order[0]], vectors[order[1]], vectors[order[2]]],
+        };
+        (eigen, converged)
+    }
+}
+
+/// Returns the cosine and sine of the rotation that zeroes the element `apq`
+fn jacobi_rotation<T: Float>(app: T, aqq: T, apq: T) -> (T, T) {
+    let two = T::one() + T::one();
+    let theta = (aqq - app) / (two * apq);
+    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+    let c = T::one() / (t * t + T::one()).sqrt();
+    (c, t * c)
+}
+
+fn off_diagonal_norm<T: Float>(a: &[[T; 3]; 3]) -> T {
+    (a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2]).sqrt()
+}
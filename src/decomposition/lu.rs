@@ -0,0 +1,195 @@
+use crate::error::{ShapeError, SolveError};
+use crate::matrix::{DMatrix, Layout, MatrixMN};
+use crate::vector::{DVector, VectorN};
+
+use num_traits::Float;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+use super::Lu;
+
+impl<T> Lu<T>
+    where T: Float
+{
+    /// Decomposes a square matrix
+    ///
+    /// Pivots smaller than the largest element times the machine epsilon and the size
+    /// are treated as zero, `singular_pivot` reports the first column where that happened.
+    pub fn new(matrix: &DMatrix<T>) -> Result<Self, ShapeError> {
+        let size = matrix.rows();
+        if matrix.columns() != size {
+            return Err(ShapeError::new([size, size], matrix.shape()));
+        }
+
+        let mut values = matrix.to_layout(Layout::RowMajor).as_slice().to_vec();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut odd_swaps = false;
+        let mut singular_pivot = None;
+
+        let largest = values.iter().fold(T::zero(), |largest, value| largest.max(value.abs()));
+        let tolerance = largest * T::epsilon() * T::from(size).unwrap();
+
+        for k in 0..size {
+            // Partial pivoting, moves the largest remaining element of the column to the diagonal
+            let pivot_row = (k..size)
+                .max_by(|&a, &b| values[a * size + k].abs().partial_cmp(&values[b * size + k].abs()).unwrap_or(Ordering::Equal))
+                .unwrap();
+            if pivot_row != k {
+                for j in 0..size {
+                    values.swap(k * size + j, pivot_row * size + j);
+                }
+                permutation.swap(k, pivot_row);
+                odd_swaps = !odd_swaps;
+            }
+
+            let pivot = values[k * size + k];
+            if pivot.abs() <= tolerance && singular_pivot.is_none() {
+                singular_pivot = Some(k);
+            }
+            if pivot == T::zero() {
+                continue;
+            }
+
+            for i in (k + 1)..size {
+                let factor = values[i * size + k] / pivot;
+                values[i * size + k] = factor;
+                for j in (k + 1)..size {
+                    values[i * size + j] = values[i * size + j] - factor * values[k * size + j];
+                }
+            }
+        }
+
+        Ok(Self { size, values, permutation, odd_swaps, singular_pivot })
+    }
+    /// Returns the lower triangular factor
+    pub fn l(&self) -> DMatrix<T> {
+        DMatrix::from_fn(self.size, self.size, |i, j| {
+            if i == j { T::one() } else if i > j { self.values[i * self.size + j] } else { T::zero() }
+        })
+    }
+    /// Returns the upper triangular factor
+    pub fn u(&self) -> DMatrix<T> {
+        DMatrix::from_fn(self.size, self.size, |i, j| {
+            if i <= j { self.values[i * self.size + j] } else { T::zero() }
+        })
+    }
+    /// Returns the permutation matrix `P`
+    pub fn p(&self) -> DMatrix<T> {
+        DMatrix::from_fn(self.size, self.size, |i, j| {
+            if self.permutation[i] == j { T::one() } else { T::zero() }
+        })
+    }
+    /// Returns the row permutation, row `i` of `P * A` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+    /// Returns the first column without a usable pivot, if the matrix is singular
+    pub fn singular_pivot(&self) -> Option<usize> {
+        self.singular_pivot
+    }
+    pub fn is_singular(&self) -> bool {
+        self.singular_pivot.is_some()
+    }
+    /// Returns the determinant of the decomposed matrix
+    pub fn determinant(&self) -> T {
+        let product = (0..self.size).fold(T::one(), |product, i| product * self.values[i * self.size + i]);
+        if self.odd_swaps { -product } else { product }
+    }
+    /// Solves `A * x = b` for `x`
+    pub fn solve(&self, b: &DVector<T>) -> Result<DVector<T>, SolveError> {
+        if b.values.len() != self.size {
+            return Err(ShapeError::new([self.size, 1], [b.values.len(), 1]).into());
+        }
+        self.check_singular()?;
+        let mut x: Vec<T> = self.permutation.iter().map(|&i| b[i]).collect();
+        self.substitute(&mut x);
+        Ok(DVector::new(x))
+    }
+    /// Solves `A * X = B` for `X`, one column of `B` at a time
+    pub fn solve_matrix(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, SolveError> {
+        if b.rows() != self.size {
+            return Err(ShapeError::new([self.size, b.columns()], b.shape()).into());
+        }
+        self.check_singular()?;
+        let mut values = Vec::with_capacity(b.rows() * b.columns());
+        for j in 0..b.columns() {
+            let mut x: Vec<T> = self.permutation.iter().map(|&i| b[[i, j]]).collect();
+            self.substitute(&mut x);
+            values.extend(x);
+        }
+        Ok(DMatrix::from_column_major(b.rows(), b.columns(), values)?)
+    }
+    /// Returns the inverse of the decomposed matrix
+    pub fn inverse(&self) -> Result<DMatrix<T>, SolveError> {
+        self.solve_matrix(&DMatrix::identity(self.size))
+    }
+    fn check_singular(&self) -> Result<(), SolveError> {
+        match self.singular_pivot {
+            Some(column) => Err(SolveError::Singular(column)),
+            None => Ok(()),
+        }
+    }
+    /// Solves `L * U * x = y` in place by forward and back substitution
+    fn substitute(&self, x: &mut [T]) {
+        let n = self.size;
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i] - self.values[i * n + j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                x[i] = x[i] - self.values[i * n + j] * x[j];
+            }
+            x[i] = x[i] / self.values[i * n + i];
+        }
+    }
+}
+
+impl<T> DMatrix<T>
+    where T: Float
+{
+    /// Returns the LU decomposition of a square matrix
+    pub fn lu(&self) -> Result<Lu<T>, ShapeError> {
+        Lu::new(self)
+    }
+    /// Solves `self * x = b` for `x`
+    pub fn solve(&self, b: &DVector<T>) -> Result<DVector<T>, SolveError> {
+        self.lu()?.solve(b)
+    }
+    /// Solves `self * X = B` for `X`
+    pub fn solve_matrix(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, SolveError> {
+        self.lu()?.solve_matrix(b)
+    }
+    /// Returns the determinant of a square matrix
+    pub fn determinant(&self) -> Result<T, ShapeError> {
+        Ok(self.lu()?.determinant())
+    }
+    /// Returns the inverse of a square matrix
+    pub fn try_inverse(&self) -> Result<DMatrix<T>, SolveError> {
+        self.lu()?.inverse()
+    }
+}
+
+impl<T, const N: usize> MatrixMN<T, N, N>
+    where T: Float
+{
+    /// Returns the LU decomposition of the matrix
+    pub fn lu(&self) -> Lu<T> {
+        Lu::new(&DMatrix::from(*self)).unwrap()
+    }
+    /// Solves `self * x = b` for `x`
+    ///
+    /// Works with both `VectorN` and the vector type of the same size, like `Vector3` for a `Matrix3`
+    pub fn solve<V>(&self, b: V) -> Result<V, SolveError>
+        where V: Into<VectorN<T, N>> + From<VectorN<T, N>>
+    {
+        let x = self.lu().solve(&DVector::from(b.into()))?;
+        Ok(V::from(VectorN::try_from(x)?))
+    }
+    /// Solves `self * X = B` for `X`
+    pub fn solve_matrix<const K: usize>(&self, b: MatrixMN<T, N, K>) -> Result<MatrixMN<T, N, K>, SolveError> {
+        let x = self.lu().solve_matrix(&DMatrix::from(b))?;
+        Ok(MatrixMN::try_from(x)?)
+    }
+}
@@ -0,0 +1,192 @@
+use crate::error::{ShapeError, SolveError};
+use crate::matrix::{DMatrix, MatrixMN};
+use crate::vector::{DVector, VectorN};
+
+use num_traits::Float;
+use std::convert::TryFrom;
+
+use super::{Qr, dot, norm};
+
+impl<T> Qr<T>
+    where T: Float
+{
+    /// Decomposes a matrix using Householder reflections
+    pub fn householder(matrix: &DMatrix<T>) -> Self {
+        let (rows, columns) = (matrix.rows(), matrix.columns());
+        let size = rows.min(columns);
+        let mut r = DMatrix::from_fn(rows, columns, |i, j| matrix[[i, j]]);
+        let mut reflectors = Vec::with_capacity(size);
+
+        for k in 0..size {
+            // The reflection maps column k below the diagonal onto the first axis
+            let mut v: Vec<T> = (k..rows).map(|i| r[[i, k]]).collect();
+            let column_norm = norm(&v);
+            let alpha = if v[0] > T::zero() { -column_norm } else { column_norm };
+            v[0] = v[0] - alpha;
+            let length = norm(&v);
+            if length > T::zero() {
+                v.iter_mut().for_each(|value| *value = *value / length);
+                reflect(&v, k, &mut r, k);
+            }
+            reflectors.push(v);
+        }
+
+        // Q is built by applying the reflections to the first columns of the identity
+        let mut q = DMatrix::from_fn(rows, size, |i, j| if i == j { T::one() } else { T::zero() });
+        for (k, v) in reflectors.iter().enumerate().rev() {
+            reflect(v, k, &mut q, 0);
+        }
+        let r = DMatrix::from_fn(size, columns, |i, j| if i <= j { r[[i, j]] } else { T::zero() });
+
+        Self { q, r, tolerance: tolerance(matrix) }
+    }
+    /// Decomposes a matrix using the modified Gram-Schmidt process
+    ///
+    /// Cheaper than `householder` but `Q` loses orthogonality for ill-conditioned matrices
+    pub fn gram_schmidt(matrix: &DMatrix<T>) -> Self {
+        let (rows, columns) = (matrix.rows(), matrix.columns());
+        let size = rows.min(columns);
+        let mut vectors: Vec<DVector<T>> = (0..columns).map(|j| matrix.column_vector(j)).collect();
+        let mut q = DMatrix::zeros(rows, size);
+        let mut r = DMatrix::zeros(size, columns);
+        let tolerance = tolerance(matrix);
+
+        for k in 0..size {
+            let length = norm(vectors[k].as_slice());
+            r[[k, k]] = length;
+            if length <= tolerance {
+                continue;
+            }
+            for i in 0..rows {
+                q[[i, k]] = vectors[k][i] / length;
+            }
+            for j in (k + 1)..columns {
+                let projection = (0..rows).fold(T::zero(), |sum, i| sum + q[[i, k]] * vectors[j][i]);
+                r[[k, j]] = projection;
+                for i in 0..rows {
+                    vectors[j][i] = vectors[j][i] - projection * q[[i, k]];
+                }
+            }
+        }
+
+        Self { q, r, tolerance }
+    }
+    /// Returns the factor with orthonormal columns, with as many columns as `R` has rows
+    pub fn q(&self) -> &DMatrix<T> {
+        &self.q
+    }
+    /// Returns the upper triangular factor
+    pub fn r(&self) -> &DMatrix<T> {
+        &self.r
+    }
+    /// Returns the least squares solution of `A * x = b` and the norm of the residual `A * x - b`
+    ///
+    /// Needs at least as many rows as columns and linearly independent columns
+    pub fn least_squares(&self, b: &DVector<T>) -> Result<(DVector<T>, T), SolveError> {
+        let (rows, size) = (self.q.rows(), self.q.columns());
+        let columns = self.r.columns();
+        if b.values.len() != rows {
+            return Err(ShapeError::new([rows, 1], [b.values.len(), 1]).into());
+        }
+        if size < columns {
+            return Err(ShapeError::new([columns, columns], [rows, columns]).into());
+        }
+        if let Some(k) = (0..columns).find(|&k| self.r[[k, k]].abs() <= self.tolerance) {
+            return Err(SolveError::Singular(k));
+        }
+
+        let qtb: Vec<T> = (0..size).map(|j| (0..rows).fold(T::zero(), |sum, i| sum + self.q[[i, j]] * b[i])).collect();
+        let mut x = qtb.clone();
+        for i in (0..columns).rev() {
+            for j in (i + 1)..columns {
+                x[i] = x[i] - self.r[[i, j]] * x[j];
+            }
+            x[i] = x[i] / self.r[[i, i]];
+        }
+
+        // The residual is the part of b outside the column space of Q
+        let residual: Vec<T> = (0..rows)
+            .map(|i| b[i] - (0..size).fold(T::zero(), |sum, j| sum + self.q[[i, j]] * qtb[j]))
+            .collect();
+
+        Ok((DVector::new(x), norm(&residual)))
+    }
+}
+
+/// Returns the least squares solution of `a * x = b` and the norm of the residual `a * x - b`
+pub fn least_squares<T: Float>(a: &DMatrix<T>, b: &DVector<T>) -> Result<(DVector<T>, T), SolveError> {
+    Qr::householder(a).least_squares(b)
+}
+
+/// Orthonormalizes the vectors with the modified Gram-Schmidt process
+///
+/// Vectors that are linearly dependent on the ones before them are left out
+pub fn gram_schmidt<T, V, const N: usize>(vectors: &[V]) -> Vec<V>
+    where T: Float, V: Copy + Into<VectorN<T, N>> + From<VectorN<T, N>>
+{
+    let vectors: Vec<VectorN<T, N>> = vectors.iter().map(|vec| (*vec).into()).collect();
+    let largest = vectors.iter().map(|vec| norm(&vec.values)).fold(T::zero(), T::max);
+    let tolerance = largest * T::epsilon() * T::from(N.max(vectors.len())).unwrap();
+    let mut basis: Vec<VectorN<T, N>> = Vec::new();
+
+    for mut vec in vectors {
+        for axis in &basis {
+            let projection = dot(&axis.values, &vec.values);
+            for i in 0..N {
+                vec.values[i] = vec.values[i] - projection * axis.values[i];
+            }
+        }
+        let length = norm(&vec.values);
+        if length > tolerance {
+            vec.values.iter_mut().for_each(|value| *value = *value / length);
+            basis.push(vec);
+        }
+    }
+    basis.into_iter().map(V::from).collect()
+}
+
+impl<T> DMatrix<T>
+    where T: Float
+{
+    /// Returns the QR decomposition of the matrix, using Householder reflections
+    pub fn qr(&self) -> Qr<T> {
+        Qr::householder(self)
+    }
+    /// Returns the least squares solution of `self * x = b` and the norm of the residual
+    pub fn least_squares(&self, b: &DVector<T>) -> Result<(DVector<T>, T), SolveError> {
+        least_squares(self, b)
+    }
+}
+
+impl<T, const R: usize, const C: usize> MatrixMN<T, R, C>
+    where T: Float
+{
+    /// Returns the QR decomposition of the matrix, using Householder reflections
+    pub fn qr(&self) -> Qr<T> {
+        Qr::householder(&DMatrix::from(*self))
+    }
+    /// Returns the least squares solution of `self * x = b` and the norm of the residual
+    pub fn least_squares<V>(&self, b: V) -> Result<(VectorN<T, C>, T), SolveError>
+        where V: Into<VectorN<T, R>>
+    {
+        let (x, residual) = self.qr().least_squares(&DVector::from(b.into()))?;
+        Ok((VectorN::try_from(x)?, residual))
+    }
+}
+
+/// Applies the reflection `I - 2 * v * vᵀ` to the rows from `start` down and the columns from `column` on
+fn reflect<T: Float>(v: &[T], start: usize, matrix: &mut DMatrix<T>, column: usize) {
+    let two = T::one() + T::one();
+    for j in column..matrix.columns() {
+        let projection = v.iter().enumerate().fold(T::zero(), |sum, (i, value)| sum + *value * matrix[[start + i, j]]);
+        for (i, value) in v.iter().enumerate() {
+            matrix[[start + i, j]] = matrix[[start + i, j]] - two * projection * *value;
+        }
+    }
+}
+
+/// Diagonal elements of `R` at or below this are treated as zero
+fn tolerance<T: Float>(matrix: &DMatrix<T>) -> T {
+    let largest = matrix.as_slice().iter().fold(T::zero(), |largest, value| largest.max(value.abs()));
+    largest * T::epsilon() * T::from(matrix.rows().max(matrix.columns())).unwrap()
+}
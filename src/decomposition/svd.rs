@@ -0,0 +1,193 @@
+use crate::matrix::{DMatrix, MatrixMN};
+use crate::vector::DVector;
+
+use num_traits::Float;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+use super::{Svd, dot, norm, unit};
+
+/// The number of sweeps after which the Jacobi rotations stop even without convergence
+const MAX_SWEEPS: usize = 100;
+
+impl<T> Svd<T>
+    where T: Float
+{
+    /// Decomposes a matrix using one-sided Jacobi rotations
+    pub fn new(matrix: &DMatrix<T>) -> Self {
+        if matrix.rows() < matrix.columns() {
+            // A = U * Σ * Vᵀ is found from Aᵀ = V * Σ * Uᵀ
+            let svd = Self::new(&matrix.transpose());
+            return Self { u: svd.v_t.transpose(), singular_values: svd.singular_values, v_t: svd.u.transpose() };
+        }
+
+        let (rows, columns) = (matrix.rows(), matrix.columns());
+        let mut u: Vec<Vec<T>> = (0..columns).map(|j| matrix.column_vector(j).values).collect();
+        let mut v: Vec<Vec<T>> = (0..columns).map(|j| unit(columns, j)).collect();
+
+        // Rotates pairs of columns until all of them are orthogonal, U * Σ is then A * V
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..columns {
+                for q in (p + 1)..columns {
+                    let alpha = dot(&u[p], &u[p]);
+                    let beta = dot(&u[q], &u[q]);
+                    let gamma = dot(&u[p], &u[q]);
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let two = T::one() + T::one();
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+                    rotate(&mut u, p, q, c, s);
+                    rotate(&mut v, p, q, c, s);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order: Vec<usize> = (0..columns).collect();
+        let norms: Vec<T> = u.iter().map(|column| norm(column)).collect();
+        order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+
+        let largest = norms.iter().fold(T::zero(), |largest, norm| largest.max(*norm));
+        let tolerance = largest * T::epsilon() * T::from(rows).unwrap();
+        let mut basis: Vec<Vec<T>> = Vec::with_capacity(columns);
+        for &j in &order {
+            if norms[j] > tolerance {
+                basis.push(u[j].iter().map(|value| *value / norms[j]).collect());
+            }
+            else {
+                basis.push(complete(&basis, rows));
+            }
+        }
+
+        Self {
+            u: DMatrix::from_fn(rows, columns, |i, j| basis[j][i]),
+            singular_values: DVector::new(order.iter().map(|&j| norms[j]).collect()),
+            v_t: DMatrix::from_fn(columns, columns, |i, j| v[order[i]][j]),
+        }
+    }
+    /// Returns the left singular vectors as columns
+    pub fn u(&self) -> &DMatrix<T> {
+        &self.u
+    }
+    /// Returns the singular values, sorted from largest to smallest
+    pub fn singular_values(&self) -> &DVector<T> {
+        &self.singular_values
+    }
+    /// Returns `Σ` as a diagonal matrix
+    pub fn sigma(&self) -> DMatrix<T> {
+        let size = self.singular_values.values.len();
+        DMatrix::from_fn(size, size, |i, j| if i == j { self.singular_values[i] } else { T::zero() })
+    }
+    /// Returns the right singular vectors as rows
+    pub fn v_t(&self) -> &DMatrix<T> {
+        &self.v_t
+    }
+    /// Returns the number of singular values larger than `eps`
+    pub fn rank(&self, eps: T) -> usize {
+        self.singular_values.values.iter().filter(|value| **value > eps).count()
+    }
+    /// Returns the ratio between the largest and the smallest singular value
+    ///
+    /// Infinite for singular matrices, which includes the zero matrix and empty matrices
+    pub fn condition_number(&self) -> T {
+        match (self.singular_values.values.first(), self.singular_values.values.last()) {
+            (Some(&largest), Some(&smallest)) if smallest > T::zero() => largest / smallest,
+            _ => T::infinity(),
+        }
+    }
+    /// Returns the Moore-Penrose pseudo-inverse, `V * Σ⁺ * Uᵀ`
+    ///
+    /// Singular values at or below `eps` are treated as zero
+    pub fn pseudo_inverse(&self, eps: T) -> DMatrix<T> {
+        let (rows, columns) = (self.u.rows(), self.v_t.columns());
+        let size = self.singular_values.values.len();
+        DMatrix::from_fn(columns, rows, |i, j| {
+            (0..size)
+                .filter(|&k| self.singular_values[k] > eps)
+                .fold(T::zero(), |sum, k| sum + self.v_t[[k, i]] * self.u[[j, k]] / self.singular_values[k])
+        })
+    }
+}
+
+impl<T> DMatrix<T>
+    where T: Float
+{
+    /// Returns the singular value decomposition of the matrix
+    pub fn svd(&self) -> Svd<T> {
+        Svd::new(self)
+    }
+    /// Returns the number of singular values larger than `eps`
+    pub fn rank(&self, eps: T) -> usize {
+        self.svd().rank(eps)
+    }
+    /// Returns the ratio between the largest and the smallest singular value
+    ///
+    /// Infinite for singular matrices, see `Svd::condition_number`
+    pub fn condition_number(&self) -> T {
+        self.svd().condition_number()
+    }
+    /// Returns the Moore-Penrose pseudo-inverse, singular values at or below `eps` are treated as zero
+    pub fn pseudo_inverse(&self, eps: T) -> DMatrix<T> {
+        self.svd().pseudo_inverse(eps)
+    }
+}
+
+impl<T, const R: usize, const C: usize> MatrixMN<T, R, C>
+    where T: Float
+{
+    /// Returns the singular value decomposition of the matrix
+    pub fn svd(&self) -> Svd<T> {
+        Svd::new(&DMatrix::from(*self))
+    }
+    /// Returns the number of singular values larger than `eps`
+    pub fn rank(&self, eps: T) -> usize {
+        self.svd().rank(eps)
+    }
+    /// Returns the ratio between the largest and the smallest singular value
+    ///
+    /// Infinite for singular matrices, see `Svd::condition_number`
+    pub fn condition_number(&self) -> T {
+        self.svd().condition_number()
+    }
+    /// Returns the Moore-Penrose pseudo-inverse, singular values at or below `eps` are treated as zero
+    pub fn pseudo_inverse(&self, eps: T) -> MatrixMN<T, C, R> {
+        MatrixMN::try_from(self.svd().pseudo_inverse(eps)).unwrap()
+    }
+}
+
+/// Applies a rotation in the plane of the columns `p` and `q`
+fn rotate<T: Float>(columns: &mut [Vec<T>], p: usize, q: usize, c: T, s: T) {
+    for i in 0..columns[p].len() {
+        let (x, y) = (columns[p][i], columns[q][i]);
+        columns[p][i] = c * x - s * y;
+        columns[q][i] = s * x + c * y;
+    }
+}
+
+/// Returns a unit vector orthogonal to all vectors in `basis`
+///
+/// Used for the left singular vectors of zero singular values
+fn complete<T: Float>(basis: &[Vec<T>], len: usize) -> Vec<T> {
+    let half = T::from(0.5).unwrap();
+    for axis in 0..len {
+        let mut vec = unit(len, axis);
+        for other in basis {
+            let projection = dot(other, &vec);
+            vec.iter_mut().zip(other.iter()).for_each(|(value, other)| *value = *value - projection * *other);
+        }
+        let length = norm(&vec);
+        if length > half {
+            return vec.iter().map(|value| *value / length).collect();
+        }
+    }
+    vec![T::zero(); len]
+}
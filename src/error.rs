@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Returned when the shape of a dynamically sized matrix or vector does not fit the operation.
+///
+/// Shapes are given as `[rows, columns]`, vectors count as a single column.
+pub struct ShapeError {
+    pub expected: [usize; 2],
+    pub found: [usize; 2],
+}
+
+impl ShapeError {
+    pub fn new(expected: [usize; 2], found: [usize; 2]) -> Self {
+        Self { expected, found }
+    }
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected a shape of {}x{}, found {}x{}",
+            self.expected[0], self.expected[1], self.found[0], self.found[1]
+        )
+    }
+}
+
+impl Error for ShapeError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Returned when a linear system can not be solved.
+pub enum SolveError {
+    /// The right hand side does not fit the matrix
+    Shape(ShapeError),
+    /// The matrix is singular, the value is the column where no usable pivot was found
+    Singular(usize),
+}
+
+impl From<ShapeError> for SolveError {
+    fn from(error: ShapeError) -> Self {
+        SolveError::Shape(error)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Shape(error) => write!(f, "{}", error),
+            SolveError::Singular(column) => write!(f, "the matrix is singular, no pivot found in column {}", column),
+        }
+    }
+}
+
+impl Error for SolveError {}
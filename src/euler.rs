@@ -0,0 +1,164 @@
+use crate::angles::Rad;
+use crate::matrix::{Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::vector::{Unit, Vector3};
+
+use num_traits::Float;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The axes and order of the three rotations of a set of euler angles.
+///
+/// The rotations are intrinsic and applied in the order they are named, `ZYX` rotates
+/// around Z first, then around the new Y and last around the new X axis.
+/// The first six orders are Tait-Bryan angles, the last six are proper euler angles.
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerOrder {
+    /// Returns the indexes of the axes of the three rotations, 0 being X, 1 Y and 2 Z
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+    /// Returns true if the first and last rotation are around the same axis
+    pub fn is_proper(&self) -> bool {
+        let axes = self.axes();
+        axes[0] == axes[2]
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// Three rotations around the axes given by `order`.
+pub struct EulerAngles<T>
+    where T: Float
+{
+    pub first: Rad<T>,
+    pub second: Rad<T>,
+    pub third: Rad<T>,
+    pub order: EulerOrder,
+}
+
+impl<T> EulerAngles<T>
+    where T: Float
+{
+    pub fn new<A, B, C>(first: A, second: B, third: C, order: EulerOrder) -> Self
+        where A: Into<Rad<T>>, B: Into<Rad<T>>, C: Into<Rad<T>>
+    {
+        Self {
+            first: first.into(),
+            second: second.into(),
+            third: third.into(),
+            order,
+        }
+    }
+    /// Returns the quaternion representing the same rotation
+    pub fn to_quaternion(&self) -> Quaternion<T> {
+        let axes = self.order.axes();
+        Quaternion::from_axis_angle(unit_axis(axes[0]), self.first)
+            * Quaternion::from_axis_angle(unit_axis(axes[1]), self.second)
+            * Quaternion::from_axis_angle(unit_axis(axes[2]), self.third)
+    }
+    /// Returns the rotation matrix representing the same rotation
+    pub fn to_matrix3(&self) -> Matrix3<T> {
+        self.to_quaternion().to_matrix3()
+    }
+    /// Returns the 4x4 rotation matrix representing the same rotation
+    pub fn to_matrix4(&self) -> Matrix4<T> {
+        self.to_quaternion().to_matrix4()
+    }
+    /// Extracts euler angles in the given order from a rotation matrix
+    ///
+    /// The second value is true if the rotation is in gimbal lock, the first and third axis
+    /// then line up and only their combined angle can be recovered. In that case
+    /// the third angle is set to zero and the first angle holds the whole rotation.
+    pub fn from_matrix3(matrix: Matrix3<T>, order: EulerOrder) -> (Self, bool) {
+        let m = matrix.values;
+        let [i, j, _] = order.axes();
+        let k = 3 - i - j;
+        // Positive if the axes i, j, k are in cyclic order
+        let sign = if (i + 1) % 3 == j { T::one() } else { -T::one() };
+        let threshold = T::epsilon().sqrt();
+
+        let (first, second, third, locked);
+        if order.is_proper() {
+            let sin = (m[i][j] * m[i][j] + m[i][k] * m[i][k]).sqrt();
+            second = Rad::atan2(sin, m[i][i]);
+            locked = sin < threshold;
+
+            if locked {
+                first = Rad::atan2(sign * m[k][j], m[j][j]);
+                third = Rad(T::zero());
+            }
+            else {
+                first = Rad::atan2(m[j][i], -sign * m[k][i]);
+                third = Rad::atan2(m[i][j], sign * m[i][k]);
+            }
+        }
+        else {
+            let cos = (m[i][i] * m[i][i] + m[i][j] * m[i][j]).sqrt();
+            second = Rad::atan2(sign * m[i][k], cos);
+            locked = cos < threshold;
+
+            if locked {
+                first = Rad::atan2(sign * m[k][j], m[j][j]);
+                third = Rad(T::zero());
+            }
+            else {
+                first = Rad::atan2(-sign * m[j][k], m[k][k]);
+                third = Rad::atan2(-sign * m[i][j], m[i][i]);
+            }
+        }
+
+        (Self::new(first, second, third, order), locked)
+    }
+    /// Extracts euler angles in the given order from the rotation part of a 4x4 matrix
+    ///
+    /// See `from_matrix3` for the meaning of the second value
+    pub fn from_matrix4(matrix: Matrix4<T>, order: EulerOrder) -> (Self, bool) {
+        let m = matrix.values;
+        Self::from_matrix3(
+            Matrix3::new([
+                [m[0][0], m[0][1], m[0][2]],
+                [m[1][0], m[1][1], m[1][2]],
+                [m[2][0], m[2][1], m[2][2]],
+            ]),
+            order,
+        )
+    }
+    /// Extracts euler angles in the given order from a normalized quaternion
+    ///
+    /// See `from_matrix3` for the meaning of the second value
+    pub fn from_quaternion(quaternion: Quaternion<T>, order: EulerOrder) -> (Self, bool) {
+        Self::from_matrix3(quaternion.to_matrix3(), order)
+    }
+}
+
+fn unit_axis<T: Float>(index: usize) -> Unit<Vector3<T>> {
+    let mut axis = Vector3::zero();
+    axis[index] = T::one();
+    Unit::new_unchecked(axis)
+}
@@ -1,10 +1,19 @@
+#[macro_use]
+mod utils;
+
 #[path = "vector/vector.rs"]
 pub mod vector;
 
 #[path = "matrix/matrix.rs"]
 pub mod matrix;
 
+#[path = "decomposition/decomposition.rs"]
+pub mod decomposition;
+
 pub mod angles;
 pub mod coords;
-
-mod utils;
\ No newline at end of file
+pub mod error;
+pub mod euler;
+pub mod point;
+pub mod quaternion;
+pub mod transform;
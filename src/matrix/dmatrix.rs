@@ -0,0 +1,346 @@
+use crate::vector::DVector;
+use crate::error::ShapeError;
+
+use num_traits::Num;
+use std::convert::TryFrom;
+use std::ops::{Add, Mul, Sub, Index, IndexMut};
+
+use super::{DMatrix, Layout, MatrixMN};
+
+impl<T> DMatrix<T>
+    where T: Num + Copy
+{
+    /// Creates a matrix from values given one row after another
+    pub fn from_row_major(rows: usize, columns: usize, values: Vec<T>) -> Result<Self, ShapeError> {
+        Self::from_layout(rows, columns, Layout::RowMajor, values)
+    }
+    /// Creates a matrix from values given one column after another
+    pub fn from_column_major(rows: usize, columns: usize, values: Vec<T>) -> Result<Self, ShapeError> {
+        Self::from_layout(rows, columns, Layout::ColumnMajor, values)
+    }
+    /// Creates a matrix from values stored in the given layout
+    pub fn from_layout(rows: usize, columns: usize, layout: Layout, values: Vec<T>) -> Result<Self, ShapeError> {
+        if values.len() != rows * columns {
+            return Err(ShapeError::new([rows * columns, 1], [values.len(), 1]));
+        }
+        Ok(Self { rows, columns, layout, values })
+    }
+    /// Creates a matrix by calling `f` with the row and column of every element
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, columns: usize, mut f: F) -> Self {
+        let mut values = Vec::with_capacity(rows * columns);
+        for i in 0..rows {
+            for j in 0..columns {
+                values.push(f(i, j));
+            }
+        }
+        Self { rows, columns, layout: Layout::RowMajor, values }
+    }
+    /// Returns a matrix of the given size filled with zeros
+    pub fn zeros(rows: usize, columns: usize) -> Self {
+        Self { rows, columns, layout: Layout::RowMajor, values: vec![T::zero(); rows * columns] }
+    }
+    /// Returns an identity matrix of the given size
+    pub fn identity(size: usize) -> Self {
+        Self::from_fn(size, size, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+    /// Returns a matrix of the given size with all elements set to the given value
+    pub fn splat(rows: usize, columns: usize, value: T) -> Self {
+        Self { rows, columns, layout: Layout::RowMajor, values: vec![value; rows * columns] }
+    }
+    /// Returns a square matrix with the given values on the diagonal and zeros elsewhere
+    pub fn from_diagonal(diagonal: &DVector<T>) -> Self {
+        let size = diagonal.values.len();
+        Self::from_fn(size, size, |i, j| if i == j { diagonal[i] } else { T::zero() })
+    }
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+    /// Returns the size of the matrix as `[rows, columns]`
+    pub fn shape(&self) -> [usize; 2] {
+        [self.rows, self.columns]
+    }
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+    /// Returns the values in the order given by `layout`
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+    /// Returns the values one row after another, or `None` if the matrix is stored column-major
+    ///
+    /// Use `to_layout(Layout::RowMajor)` first to get the slice regardless of layout
+    pub fn as_row_major_slice(&self) -> Option<&[T]> {
+        match self.layout {
+            Layout::RowMajor => Some(&self.values),
+            Layout::ColumnMajor => None,
+        }
+    }
+    /// Returns the values one column after another, or `None` if the matrix is stored row-major
+    ///
+    /// Use `to_layout(Layout::ColumnMajor)` first to get the slice regardless of layout
+    pub fn as_column_major_slice(&self) -> Option<&[T]> {
+        match self.layout {
+            Layout::RowMajor => None,
+            Layout::ColumnMajor => Some(&self.values),
+        }
+    }
+    /// Returns the same matrix with its values stored in the given layout
+    pub fn to_layout(&self, layout: Layout) -> Self {
+        if layout == self.layout {
+            return self.clone();
+        }
+        let values = match layout {
+            Layout::RowMajor => self.row_major_values(),
+            Layout::ColumnMajor => self.transpose().row_major_values(),
+        };
+        Self { rows: self.rows, columns: self.columns, layout, values }
+    }
+    /// Returns the transpose of the matrix
+    ///
+    /// Only switches the layout, the values are not moved
+    pub fn transpose(&self) -> Self {
+        let layout = match self.layout {
+            Layout::RowMajor => Layout::ColumnMajor,
+            Layout::ColumnMajor => Layout::RowMajor,
+        };
+        Self { rows: self.columns, columns: self.rows, layout, values: self.values.clone() }
+    }
+    /// Returns the element at the given row and column, or `None` if it is out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<T> {
+        if row >= self.rows || column >= self.columns {
+            return None;
+        }
+        Some(self.values[self.offset(row, column)])
+    }
+    /// Returns the given row as a vector
+    pub fn row(&self, row: usize) -> DVector<T> {
+        DVector::new((0..self.columns).map(|j| self.values[self.offset(row, j)]).collect())
+    }
+    /// Returns the given column as a vector
+    pub fn col(&self, column: usize) -> DVector<T> {
+        DVector::new((0..self.rows).map(|i| self.values[self.offset(i, column)]).collect())
+    }
+    /// Replaces the given row, fails if the vector does not have one element per column
+    pub fn set_row(&mut self, row: usize, vector: &DVector<T>) -> Result<(), ShapeError> {
+        if vector.values.len() != self.columns {
+            return Err(ShapeError::new([self.columns, 1], [vector.values.len(), 1]));
+        }
+        for (j, value) in vector.values.iter().enumerate() {
+            self[[row, j]] = *value;
+        }
+        Ok(())
+    }
+    /// Replaces the given column, fails if the vector does not have one element per row
+    pub fn set_col(&mut self, column: usize, vector: &DVector<T>) -> Result<(), ShapeError> {
+        if vector.values.len() != self.rows {
+            return Err(ShapeError::new([self.rows, 1], [vector.values.len(), 1]));
+        }
+        for (i, value) in vector.values.iter().enumerate() {
+            self[[i, column]] = *value;
+        }
+        Ok(())
+    }
+    /// Same as `row`
+    pub fn row_vector(&self, row: usize) -> DVector<T> {
+        self.row(row)
+    }
+    /// Same as `col`
+    pub fn column_vector(&self, column: usize) -> DVector<T> {
+        self.col(column)
+    }
+    /// Returns a view of the part of the matrix starting at the given row and column
+    ///
+    /// Panics if the view does not fit inside the matrix
+    pub fn view(&self, row: usize, column: usize, rows: usize, columns: usize) -> DMatrixView<'_, T> {
+        if row + rows > self.rows || column + columns > self.columns {
+            panic!("View out of bounds");
+        }
+        DMatrixView { matrix: self, row, column, rows, columns }
+    }
+    /// Returns the position in `values` of the element at the given row and column
+    fn offset(&self, row: usize, column: usize) -> usize {
+        if row >= self.rows || column >= self.columns {
+            panic!("Index out of bounds");
+        }
+        match self.layout {
+            Layout::RowMajor => row * self.columns + column,
+            Layout::ColumnMajor => column * self.rows + row,
+        }
+    }
+    fn row_major_values(&self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.values.len());
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                values.push(self.values[self.offset(i, j)]);
+            }
+        }
+        values
+    }
+    /// Returns the dot product of the given row and the values returned by `other` for each column
+    fn row_dot<F: Fn(usize) -> T>(&self, row: usize, other: F) -> T {
+        (0..self.columns).fold(T::zero(), |sum, k| sum + self.values[self.offset(row, k)] * other(k))
+    }
+    fn check_shape(&self, other: &Self) -> Result<(), ShapeError> {
+        if self.shape() == other.shape() {
+            Ok(())
+        }
+        else {
+            Err(ShapeError::new(self.shape(), other.shape()))
+        }
+    }
+}
+
+impl<T> PartialEq for DMatrix<T>
+    where T: Num + Copy
+{
+    /// Matrices are equal if they have the same shape and values, regardless of their layouts
+    fn eq(&self, other: &Self) -> bool {
+        self.shape() == other.shape() && self.row_major_values() == other.row_major_values()
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<MatrixMN<T, R, C>> for DMatrix<T>
+    where T: Num + Copy
+{
+    fn from(matrix: MatrixMN<T, R, C>) -> Self {
+        let values = matrix.values.iter().flat_map(|row| row.iter().copied()).collect();
+        Self { rows: R, columns: C, layout: Layout::RowMajor, values }
+    }
+}
+impl<T, const R: usize, const C: usize> TryFrom<DMatrix<T>> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    type Error = ShapeError;
+
+    fn try_from(matrix: DMatrix<T>) -> Result<Self, ShapeError> {
+        if matrix.shape() != [R, C] {
+            return Err(ShapeError::new([R, C], matrix.shape()));
+        }
+        let mut values = [[T::zero(); C]; R];
+        for (i, row) in values.iter_mut().enumerate() {
+            for (j, element) in row.iter_mut().enumerate() {
+                *element = matrix.values[matrix.offset(i, j)];
+            }
+        }
+        Ok(MatrixMN { values })
+    }
+}
+
+impl<T> Add for &DMatrix<T>
+    where T: Num + Copy
+{
+    type Output = Result<DMatrix<T>, ShapeError>;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.check_shape(other)?;
+        Ok(DMatrix::from_fn(self.rows, self.columns, |i, j| self[[i, j]] + other[[i, j]]))
+    }
+}
+impl<T> Sub for &DMatrix<T>
+    where T: Num + Copy
+{
+    type Output = Result<DMatrix<T>, ShapeError>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.check_shape(other)?;
+        Ok(DMatrix::from_fn(self.rows, self.columns, |i, j| self[[i, j]] - other[[i, j]]))
+    }
+}
+impl<T> Mul for &DMatrix<T>
+    where T: Num + Copy
+{
+    type Output = Result<DMatrix<T>, ShapeError>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        if self.columns != other.rows {
+            return Err(ShapeError::new([self.columns, other.columns], other.shape()));
+        }
+        Ok(DMatrix::from_fn(self.rows, other.columns, |i, j| self.row_dot(i, |k| other[[k, j]])))
+    }
+}
+impl<'a, T> Mul<&'a DVector<T>> for &'a DMatrix<T>
+    where T: Num + Copy
+{
+    type Output = Result<DVector<T>, ShapeError>;
+
+    fn mul(self, other: &'a DVector<T>) -> Self::Output {
+        if self.columns != other.values.len() {
+            return Err(ShapeError::new([self.columns, 1], [other.values.len(), 1]));
+        }
+        Ok(DVector::new((0..self.rows).map(|i| self.row_dot(i, |k| other[k])).collect()))
+    }
+}
+
+impl<T> Index<[usize; 2]> for DMatrix<T>
+    where T: Num + Copy
+{
+    type Output = T;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        &self.values[self.offset(index[0], index[1])]
+    }
+}
+impl<T> IndexMut<[usize; 2]> for DMatrix<T>
+    where T: Num + Copy
+{
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        let offset = self.offset(index[0], index[1]);
+        &mut self.values[offset]
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// A borrowed rectangular part of a `DMatrix`.
+///
+/// Indexing with `[[row, column]]` is relative to the top left corner of the view.
+pub struct DMatrixView<'a, T: Num + Copy> {
+    matrix: &'a DMatrix<T>,
+    row: usize,
+    column: usize,
+    rows: usize,
+    columns: usize,
+}
+
+impl<'a, T> DMatrixView<'a, T>
+    where T: Num + Copy
+{
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+    /// Returns the size of the view as `[rows, columns]`
+    pub fn shape(&self) -> [usize; 2] {
+        [self.rows, self.columns]
+    }
+    /// Returns a view of a part of this view
+    ///
+    /// Panics if the new view does not fit inside this one
+    pub fn view(&self, row: usize, column: usize, rows: usize, columns: usize) -> DMatrixView<'a, T> {
+        if row + rows > self.rows || column + columns > self.columns {
+            panic!("View out of bounds");
+        }
+        DMatrixView { matrix: self.matrix, row: self.row + row, column: self.column + column, rows, columns }
+    }
+    /// Copies the viewed values into a new matrix
+    pub fn to_matrix(&self) -> DMatrix<T> {
+        DMatrix::from_fn(self.rows, self.columns, |i, j| self[[i, j]])
+    }
+}
+
+impl<'a, T> Index<[usize; 2]> for DMatrixView<'a, T>
+    where T: Num + Copy
+{
+    type Output = T;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        if index[0] >= self.rows || index[1] >= self.columns {
+            panic!("Index out of bounds");
+        }
+        &self.matrix[[self.row + index[0], self.column + index[1]]]
+    }
+}
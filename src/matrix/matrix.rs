@@ -1,23 +1,84 @@
 pub mod matrix3;
 pub mod matrix4;
+pub mod matrix_mn;
+pub mod dmatrix;
+pub mod projection;
+mod view;
 
-use num_traits::Num;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector3;
+
+use num_traits::{Float, Num};
 
 pub trait Matrix {
     const SIZE: [usize; 2];
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
-/// A 3 by 3 matrix.
-pub struct Matrix3<T: Num + Copy> {
-    pub values: [[T; 3]; 3]
+/// A matrix with `R` rows and `C` columns.
+///
+/// The values are stored row-major, as an array of rows, so `values[row][column]`.
+/// Indexing with `[[row, column]]`, `get(row, column)`, `row` and `col` all take the row first.
+///
+/// Vectors are columns multiplied from the right, `matrix * vector`, so transformation
+/// matrices keep the translation in the last column and `a * b` applies `b` first.
+/// Use `to_cols_array` for APIs that expect column-major data, like OpenGL.
+pub struct MatrixMN<T: Num + Copy, const R: usize, const C: usize> {
+    pub values: [[T; C]; R]
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+/// A 2 by 2 matrix.
+pub type Matrix2<T> = MatrixMN<T, 2, 2>;
+/// A 3 by 3 matrix.
+pub type Matrix3<T> = MatrixMN<T, 3, 3>;
 /// A 4 by 4 matrix.
-/// 
+///
 /// #### Note
 /// Some methods and functionality only exists for certian types
-pub struct Matrix4<T: Num + Copy> {
-    pub values: [[T; 4]; 4]
-}
\ No newline at end of file
+pub type Matrix4<T> = MatrixMN<T, 4, 4>;
+
+/// A matrix with 2 rows and 3 columns.
+pub type Matrix2x3<T> = MatrixMN<T, 2, 3>;
+/// A matrix with 3 rows and 2 columns.
+pub type Matrix3x2<T> = MatrixMN<T, 3, 2>;
+/// A matrix with 3 rows and 4 columns.
+pub type Matrix3x4<T> = MatrixMN<T, 3, 4>;
+/// A matrix with 4 rows and 3 columns.
+pub type Matrix4x3<T> = MatrixMN<T, 4, 3>;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// The parts of an affine `Matrix4`, as returned by `Matrix4::decompose`.
+///
+/// The matrix is `translation * rotation * shear * scale`, the same order as
+/// `Matrix4::identity().translate(..).rotate(..).scale(..)` applies them.
+pub struct TransformParts<T: Float> {
+    pub translation: Vector3<T>,
+    /// Proper rotation matrix, with a determinant of one
+    pub rotation: Matrix3<T>,
+    /// The same rotation as `rotation`
+    pub quaternion: Quaternion<T>,
+    /// Scale along each axis, a mirroring transform gets a negative x scale
+    pub scale: Vector3<T>,
+    /// Shear factors `xy`, `xz` and `yz`, all zero for transforms built without shear
+    pub shear: Vector3<T>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The order that the values of a `DMatrix` are stored in.
+pub enum Layout {
+    /// One row after another
+    RowMajor,
+    /// One column after another
+    ColumnMajor,
+}
+
+#[derive(Debug, Clone)]
+/// A heap allocated matrix with a size only known at runtime.
+///
+/// Indexing with `[[row, column]]` takes the row first, like `MatrixMN`.
+pub struct DMatrix<T: Num + Copy> {
+    rows: usize,
+    columns: usize,
+    layout: Layout,
+    values: Vec<T>,
+}
@@ -1,9 +1,9 @@
-use super::Matrix;
-use crate::vector::{Vector, Vector3};
-use crate::vector::{ToVector3};
+use crate::vector::{Unit, Vector2, Vector3, VectorN};
+use crate::vector::ToVector2;
+use crate::angles::Rad;
 
-use num_traits::Num;
-use std::ops::{Add, Mul, Sub, Index, IndexMut};
+use num_traits::{Float, Num};
+use std::ops::Mul;
 
 use super::Matrix3;
 
@@ -11,142 +11,198 @@ impl<T> Matrix3<T>
 where
     T: Num + Copy
 {
-    pub fn new(values: [[T; 3]; 3]) -> Self {
-        Self { values }
-    }
     pub fn as_vectors_rows(&self) -> [Vector3<T>; 3] {
-        let result = [
-            Vector3::new(self[[0, 0]], self[[1, 0]], self[[2, 0]]),
-            Vector3::new(self[[0, 1]], self[[1, 1]], self[[2, 1]]),
-            Vector3::new(self[[0, 2]], self[[1, 2]], self[[2, 2]])
-        ];
-        result
-    }
-    pub fn as_vectors_collumns(&self) -> [Vector3<T>; 3] {
-        let result = [
+        [
             Vector3::new(self[[0, 0]], self[[0, 1]], self[[0, 2]]),
             Vector3::new(self[[1, 0]], self[[1, 1]], self[[1, 2]]),
             Vector3::new(self[[2, 0]], self[[2, 1]], self[[2, 2]])
-        ];
-        result
+        ]
     }
-}
-impl Matrix3<i32> {
-    /// Returns a 3x3 identity matrix
-    pub fn identity_matrix() -> Self {
-        let values: [[i32; 3]; 3] = [
-            [1, 0, 0],
-            [0, 1, 0],
-            [0, 0, 1]
-        ];
-        Self { values }
+    pub fn as_vectors_collumns(&self) -> [Vector3<T>; 3] {
+        [
+            Vector3::new(self[[0, 0]], self[[1, 0]], self[[2, 0]]),
+            Vector3::new(self[[0, 1]], self[[1, 1]], self[[2, 1]]),
+            Vector3::new(self[[0, 2]], self[[1, 2]], self[[2, 2]])
+        ]
     }
-}
-impl Matrix3<u32> {
     /// Returns a 3x3 identity matrix
     pub fn identity_matrix() -> Self {
-        let values: [[u32; 3]; 3] = [
-            [1, 0, 0],
-            [0, 1, 0],
-            [0, 0, 1]
-        ];
-        Self { values }
+        Self::identity()
+    }
+    /// Returns a homogeneous 2D translation matrix from the given vector
+    pub fn translation_2d<V: ToVector2<T>>(vector: V) -> Self {
+        let vector = vector.to_vec_2();
+        let mut matrix = Self::identity();
+        matrix[[0, 2]] = vector.x;
+        matrix[[1, 2]] = vector.y;
+        matrix
+    }
+    /// Returns a homogeneous 2D scaling matrix from the given vector
+    pub fn scaling_2d<V: ToVector2<T>>(vector: V) -> Self {
+        let vector = vector.to_vec_2();
+        let mut matrix = Self::identity();
+        matrix[[0, 0]] = vector.x;
+        matrix[[1, 1]] = vector.y;
+        matrix
+    }
+    /// Returns a homogeneous 2D shear matrix
+    ///
+    /// factors: `x` is how much x grows with y, `y` how much y grows with x
+    pub fn shear_2d<V: ToVector2<T>>(factors: V) -> Self {
+        let factors = factors.to_vec_2();
+        let mut matrix = Self::identity();
+        matrix[[0, 1]] = factors.x;
+        matrix[[1, 0]] = factors.y;
+        matrix
+    }
+    /// Returns the 2D point transformed by the matrix
+    ///
+    /// The point is extended with a `w` of one, so translations apply to it.
+    /// The result is divided by its `w`, like `Matrix4::transform_point`.
+    ///
+    /// Returns `None` if the resulting `w` is zero, as the point then lies at infinity
+    pub fn transform_point2(&self, point: Vector2<T>) -> Option<Vector2<T>> {
+        let result = *self * Vector3::new(point.x, point.y, T::one());
+        if result.z == T::zero() {
+            return None;
+        }
+        Some(Vector2::new(result.x / result.z, result.y / result.z))
+    }
+    /// Returns the 2D direction transformed by the matrix
+    ///
+    /// The vector is extended with a `w` of zero, so translations do not apply to it
+    pub fn transform_vector2(&self, vector: Vector2<T>) -> Vector2<T> {
+        let result = *self * Vector3::new(vector.x, vector.y, T::zero());
+        Vector2::new(result.x, result.y)
     }
 }
-impl Matrix3<f32> {
-    /// Returns a 3x3 identity matrix
-    pub fn identity_matrix() -> Self {
-        let values: [[f32; 3]; 3] = [
-            [1.0, 0.0, 0.0],
-            [0.0, 1.0, 0.0],
-            [0.0, 0.0, 1.0]
+impl<T> Matrix3<T>
+where
+    T: Float
+{
+    /// Returns the determinant of the matrix
+    pub fn determinant(&self) -> T {
+        let v = self.values;
+        v[0][0] * (v[1][1] * v[2][2] - v[1][2] * v[2][1])
+            - v[0][1] * (v[1][0] * v[2][2] - v[1][2] * v[2][0])
+            + v[0][2] * (v[1][0] * v[2][1] - v[1][1] * v[2][0])
+    }
+    /// Returns the adjugate of the matrix, the transpose of its cofactor matrix
+    pub fn adjugate(&self) -> Self {
+        let v = self.values;
+        let values = [
+            [
+                v[1][1] * v[2][2] - v[1][2] * v[2][1],
+                v[0][2] * v[2][1] - v[0][1] * v[2][2],
+                v[0][1] * v[1][2] - v[0][2] * v[1][1],
+            ],
+            [
+                v[1][2] * v[2][0] - v[1][0] * v[2][2],
+                v[0][0] * v[2][2] - v[0][2] * v[2][0],
+                v[0][2] * v[1][0] - v[0][0] * v[1][2],
+            ],
+            [
+                v[1][0] * v[2][1] - v[1][1] * v[2][0],
+                v[0][1] * v[2][0] - v[0][0] * v[2][1],
+                v[0][0] * v[1][1] - v[0][1] * v[1][0],
+            ],
         ];
         Self { values }
     }
-    pub fn rotation_matrix(degrees: f32, u: Vector3<f32>) -> Self {
-        let (cos, sin) = (degrees.cos(), degrees.sin());
-        
-        let mut values = [
-            [cos + u.x.powi(2) * (1.0 - cos), u.x * u.y * (1.0 - cos) - u.z * sin, u.x * u.z * (1.0 - cos) + u.y * sin],
-            [u.y * u.x * (1.0 - cos) + u.z * sin, cos + u.y.powi(2) * (1.0 - cos), u.y * u.z * (1.0 - cos) - u.x * sin],
-            [u.z * u.x * (1.0 - cos) - u.y * sin, u.z * u.y * (1.0 - cos) + u.x * sin, cos + u.z.powi(2) * (1.0 - cos)],
-        ];
+    /// Returns the inverse of the matrix
+    /// 
+    /// Returns `None` if the matrix is singular, i.e. its determinant is zero
+    pub fn try_inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant == T::zero() {
+            return None;
+        }
 
-        for row in values.iter_mut() {
+        let mut result = self.adjugate();
+        for row in result.values.iter_mut() {
             for element in row.iter_mut() {
-                if *element < 0.01 {
-                    *element = 0.0;
-                }
-                else if *element > 0.99 {
-                    *element = 1.0;
-                } 
+                *element = *element / determinant;
             }
         }
-
-        Self { values }
-    }
-    pub fn rotate(self, degrees: f32, revultion_vector: Vector3<f32>) -> Self {
-        self * Self::identity_matrix()
+        Some(result)
     }
 }
-
-impl<T> Matrix for Matrix3<T>
-where
-    T: Num + Copy
-{
-    const SIZE: [usize; 2] = [3, 3];
-}
-
-impl<T> Add for Matrix3<T>
+impl<T> Matrix3<T>
 where
-    T: Num + Copy
+    T: Float
 {
-    type Output = Self;
-
-    fn add(self, other: Self) -> Self {
-        let values: [[T; 3]; 3] = [
-            [self[[0, 0]] + other[[0, 0]], self[[1, 0]] + other[[1, 0]], self[[2, 0]] + other[[2, 0]]],
-            [self[[0, 1]] + other[[0, 1]], self[[1, 1]] + other[[1, 1]], self[[2, 1]] + other[[2, 1]]],
-            [self[[0, 2]] + other[[0, 2]], self[[1, 2]] + other[[1, 2]], self[[2, 2]] + other[[2, 2]]],
+    /// Returns a 3x3 rotation matrix
+    /// 
+    /// angle: angle for rotation, either `Rad` or `Deg`
+    /// axis: unit length axis around witch the rotation takes place
+    pub fn rotation_matrix<A: Into<Rad<T>>>(angle: A, axis: Unit<Vector3<T>>) -> Self {
+        let u = *axis;
+        let (sin, cos) = angle.into().sin_cos();
+        let t = T::one() - cos;
+
+        let values = [
+            [cos + u.x.powi(2) * t, u.x * u.y * t - u.z * sin, u.x * u.z * t + u.y * sin],
+            [u.y * u.x * t + u.z * sin, cos + u.y.powi(2) * t, u.y * u.z * t - u.x * sin],
+            [u.z * u.x * t - u.y * sin, u.z * u.y * t + u.x * sin, cos + u.z.powi(2) * t],
         ];
+
         Self { values }
     }
-}
-
-impl<T> Sub for Matrix3<T>
-where
-    T: Num + Copy
-{
-    type Output = Self;
-
-    fn sub(self, other: Self) -> Self {
-        let values: [[T; 3]; 3] = [
-            [self[[0, 0]] - other[[0, 0]], self[[1, 0]] - other[[1, 0]], self[[2, 0]] - other[[2, 0]]],
-            [self[[0, 1]] - other[[0, 1]], self[[1, 1]] - other[[1, 1]], self[[2, 1]] - other[[2, 1]]],
-            [self[[0, 2]] - other[[0, 2]], self[[1, 2]] - other[[1, 2]], self[[2, 2]] - other[[2, 2]]],
+    /// Returns self multiplied by a rotation matrix with a rotation of the given angle around the given vector.
+    pub fn rotate<A: Into<Rad<T>>>(self, angle: A, revultion_vector: Unit<Vector3<T>>) -> Self {
+        self * Self::rotation_matrix(angle, revultion_vector)
+    }
+    /// Returns a homogeneous 2D rotation matrix, a positive angle rotates counterclockwise
+    ///
+    /// angle: angle for rotation, either `Rad` or `Deg`
+    pub fn rotation_2d<A: Into<Rad<T>>>(angle: A) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        let (zero, one) = (T::zero(), T::one());
+
+        let values = [
+            [cos, -sin, zero],
+            [sin, cos, zero],
+            [zero, zero, one],
         ];
+
         Self { values }
     }
-}
-
-impl<T> Mul<Matrix3<T>> for Matrix3<T>
-where
-    T: Num + Copy
-{
-    type Output = Matrix3<T>;
+    /// Returns the inverse of a homogeneous 2D affine transformation matrix
+    ///
+    /// Such matrices have a bottom row of `[0, 0, 1]`, like the ones built from
+    /// `translation_2d`, `rotation_2d`, `scaling_2d` and `shear_2d`.
+    /// Only the upper left 2x2 part is inverted, the translation is then undone with it.
+    ///
+    /// Returns `None` if the bottom row is not `[0, 0, 1]` or the upper left 2x2 part is singular
+    pub fn affine_inverse_2d(&self) -> Option<Self> {
+        let a = self.values;
+        let (zero, one) = (T::zero(), T::one());
+        if a[2] != [zero, zero, one] {
+            return None;
+        }
+        let determinant = a[0][0] * a[1][1] - a[0][1] * a[1][0];
+        if determinant == zero {
+            return None;
+        }
 
-    fn mul(self, other: Matrix3<T>) -> Self::Output {
-        let rows1 = self.as_vectors_rows();
-        let collumns2 = self.as_vectors_collumns();
+        let inverse = [
+            [a[1][1] / determinant, -a[0][1] / determinant],
+            [-a[1][0] / determinant, a[0][0] / determinant],
+        ];
+        let translation = [
+            inverse[0][0] * a[0][2] + inverse[0][1] * a[1][2],
+            inverse[1][0] * a[0][2] + inverse[1][1] * a[1][2],
+        ];
 
-        let values: [[T; 3]; 3] = [
-            [(rows1[0] * collumns2[0]).sum(), (rows1[0] * collumns2[1]).sum(), (rows1[0] * collumns2[2]).sum()],
-            [(rows1[1] * collumns2[0]).sum(), (rows1[1] * collumns2[1]).sum(), (rows1[1] * collumns2[2]).sum()],
-            [(rows1[2] * collumns2[0]).sum(), (rows1[2] * collumns2[1]).sum(), (rows1[2] * collumns2[2]).sum()]
+        let values = [
+            [inverse[0][0], inverse[0][1], -translation[0]],
+            [inverse[1][0], inverse[1][1], -translation[1]],
+            [zero, zero, one],
         ];
-        Matrix3 { values }
+        Some(Self { values })
     }
 }
+
 impl<T> Mul<Vector3<T>> for Matrix3<T>
 where
     T: Num + Copy
@@ -154,31 +210,7 @@ where
     type Output = Vector3<T>;
 
     fn mul(self, other: Vector3<T>) -> Self::Output {
-        let rows = self.as_vectors_rows();
-        let result: Vector3<T> = Vector3::new(
-            (rows[0] * other).sum(),
-            (rows[1] * other).sum(),
-            (rows[2] * other).sum(),
-        );
-        result
+        (self * VectorN::from(other)).into()
     }
 }
 
-impl<T> Index<[usize; 2]> for Matrix3<T>
-where
-    T: Num + Copy
-{
-    type Output = T;
-
-    fn index(&self, index: [usize; 2]) -> &Self::Output {
-        &self.values[index[1]][index[0]]
-    }
-}
-impl<T> IndexMut<[usize; 2]> for Matrix3<T>
-where
-    T: Num + Copy
-{
-    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
-        &mut self.values[index[1]][index[0]]
-    }
-}
\ No newline at end of file
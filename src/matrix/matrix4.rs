@@ -1,142 +1,269 @@
-use super::Matrix;
-use crate::vector::{Vector, Vector3, Vector4};
-use crate::vector::{ToVector3};
-use crate::utils::Pad;
+use super::{Matrix3, TransformParts};
+use crate::vector::{Unit, Vector, Vector3, Vector4, VectorN};
+use crate::vector::{ToVector3, ToVector4};
+use crate::angles::Rad;
+use crate::point::Point3;
+use crate::quaternion::Quaternion;
 
-use num_traits::Num;
-use std::ops::{Add, Mul, Sub, Index, IndexMut};
-use std::fmt::{Display, Formatter};
+use num_traits::{Float, Num};
+use std::ops::Mul;
 
 use super::Matrix4;
 
 impl<T> Matrix4<T>
     where T: Num + Copy
 {
-    pub fn new(values: [[T; 4]; 4]) -> Self {
-        Matrix4 { values }
-    }
     pub fn as_vectors_rows(&self) -> [Vector4<T>; 4] {
-        let result = [
+        [
+            Vector4::new(self[[0, 0]], self[[0, 1]], self[[0, 2]], self[[0, 3]]),
+            Vector4::new(self[[1, 0]], self[[1, 1]], self[[1, 2]], self[[1, 3]]),
+            Vector4::new(self[[2, 0]], self[[2, 1]], self[[2, 2]], self[[2, 3]]),
+            Vector4::new(self[[3, 0]], self[[3, 1]], self[[3, 2]], self[[3, 3]]),
+        ]
+    }
+    pub fn as_vectors_collumns(&self) -> [Vector4<T>; 4] {
+        [
             Vector4::new(self[[0, 0]], self[[1, 0]], self[[2, 0]], self[[3, 0]]),
             Vector4::new(self[[0, 1]], self[[1, 1]], self[[2, 1]], self[[3, 1]]),
             Vector4::new(self[[0, 2]], self[[1, 2]], self[[2, 2]], self[[3, 2]]),
             Vector4::new(self[[0, 3]], self[[1, 3]], self[[2, 3]], self[[3, 3]]),
-        ];
-        result
+        ]
     }
-    pub fn as_vectors_collumns(&self) -> [Vector4<T>; 4] {
-        let result = [
-            Vector4::new(self[[0, 0]], self[[0, 1]], self[[0, 2]], self[[0, 3]]),
-            Vector4::new(self[[1, 0]], self[[1, 1]], self[[1, 2]], self[[1, 3]]),
-            Vector4::new(self[[2, 0]], self[[2, 1]], self[[2, 2]], self[[2, 3]]),
-            Vector4::new(self[[3, 0]], self[[3, 1]], self[[3, 2]], self[[3, 3]]),
+}
+impl<T> Matrix4<T>
+    where T: Float
+{
+    /// Returns the 2x2 sub-determinants of the two upper and the two lower rows,
+    /// used by both the determinant and the adjugate
+    fn sub_determinants(&self) -> ([T; 6], [T; 6]) {
+        let a = self.values;
+        let s = [
+            a[0][0] * a[1][1] - a[1][0] * a[0][1],
+            a[0][0] * a[1][2] - a[1][0] * a[0][2],
+            a[0][0] * a[1][3] - a[1][0] * a[0][3],
+            a[0][1] * a[1][2] - a[1][1] * a[0][2],
+            a[0][1] * a[1][3] - a[1][1] * a[0][3],
+            a[0][2] * a[1][3] - a[1][2] * a[0][3],
         ];
-        result
+        let c = [
+            a[2][0] * a[3][1] - a[3][0] * a[2][1],
+            a[2][0] * a[3][2] - a[3][0] * a[2][2],
+            a[2][0] * a[3][3] - a[3][0] * a[2][3],
+            a[2][1] * a[3][2] - a[3][1] * a[2][2],
+            a[2][1] * a[3][3] - a[3][1] * a[2][3],
+            a[2][2] * a[3][3] - a[3][2] * a[2][3],
+        ];
+        (s, c)
     }
-}
-impl Matrix4<i32> {
-    /// Returns a 4x4 identity matrix
-    pub fn identity_matrix() -> Matrix4<i32> {
-        let values: [[i32; 4]; 4] = [
-            [1, 0, 0, 0], 
-            [0, 1, 0, 0],
-            [0, 0, 1, 0],
-            [0, 0, 0, 1]
+    /// Returns the determinant of the matrix
+    pub fn determinant(&self) -> T {
+        let (s, c) = self.sub_determinants();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+    /// Returns the adjugate of the matrix, the transpose of its cofactor matrix
+    pub fn adjugate(&self) -> Self {
+        let a = self.values;
+        let (s, c) = self.sub_determinants();
+        let values = [
+            [
+                a[1][1] * c[5] - a[1][2] * c[4] + a[1][3] * c[3],
+                -a[0][1] * c[5] + a[0][2] * c[4] - a[0][3] * c[3],
+                a[3][1] * s[5] - a[3][2] * s[4] + a[3][3] * s[3],
+                -a[2][1] * s[5] + a[2][2] * s[4] - a[2][3] * s[3],
+            ],
+            [
+                -a[1][0] * c[5] + a[1][2] * c[2] - a[1][3] * c[1],
+                a[0][0] * c[5] - a[0][2] * c[2] + a[0][3] * c[1],
+                -a[3][0] * s[5] + a[3][2] * s[2] - a[3][3] * s[1],
+                a[2][0] * s[5] - a[2][2] * s[2] + a[2][3] * s[1],
+            ],
+            [
+                a[1][0] * c[4] - a[1][1] * c[2] + a[1][3] * c[0],
+                -a[0][0] * c[4] + a[0][1] * c[2] - a[0][3] * c[0],
+                a[3][0] * s[4] - a[3][1] * s[2] + a[3][3] * s[0],
+                -a[2][0] * s[4] + a[2][1] * s[2] - a[2][3] * s[0],
+            ],
+            [
+                -a[1][0] * c[3] + a[1][1] * c[1] - a[1][2] * c[0],
+                a[0][0] * c[3] - a[0][1] * c[1] + a[0][2] * c[0],
+                -a[3][0] * s[3] + a[3][1] * s[1] - a[3][2] * s[0],
+                a[2][0] * s[3] - a[2][1] * s[1] + a[2][2] * s[0],
+            ],
         ];
-        Matrix4::<i32> { values }
+        Self { values }
     }
-    /// Returns a 4x4 translation matrix from the given vector
-    pub fn translation_matrix<V: ToVector3<i32>>(vector: V) -> Self {
-        let mut matrix = Self::identity_matrix();
-        let vector = vector.to_vec_3();
-        for i in 0..3 {
-            matrix[[3, i]] = vector[i];
+    /// Returns the inverse of the matrix
+    /// 
+    /// Returns `None` if the matrix is singular, i.e. its determinant is zero
+    pub fn try_inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant == T::zero() {
+            return None;
         }
-        matrix
+
+        let mut result = self.adjugate();
+        for row in result.values.iter_mut() {
+            for element in row.iter_mut() {
+                *element = *element / determinant;
+            }
+        }
+        Some(result)
     }
-}
-impl Matrix4<u32> {
-    /// Returns a 4x4 identity matrix
-    pub fn identity_matrix() -> Matrix4<u32> {
-        let values: [[u32; 4]; 4] = [
-            [1, 0, 0, 0], 
-            [0, 1, 0, 0],
-            [0, 0, 1, 0],
-            [0, 0, 0, 1]
+    /// Returns the inverse of an affine transformation matrix
+    /// 
+    /// Only valid for matrices with a bottom row of `[0, 0, 0, 1]`, such as the ones
+    /// built from `translation_matrix`, `rotation_matrix` and `scaling_matrix`.
+    /// Only the upper left 3x3 part is inverted, the translation is then undone with it.
+    /// 
+    /// Returns `None` if the upper left 3x3 part is singular
+    pub fn affine_inverse(&self) -> Option<Self> {
+        let a = self.values;
+        let linear = Matrix3::new([
+            [a[0][0], a[0][1], a[0][2]],
+            [a[1][0], a[1][1], a[1][2]],
+            [a[2][0], a[2][1], a[2][2]],
+        ]);
+        let inverse = linear.try_inverse()?.values;
+        let translation = Matrix3::new(inverse) * Vector3::new(a[0][3], a[1][3], a[2][3]);
+
+        let (zero, one) = (T::zero(), T::one());
+        let values = [
+            [inverse[0][0], inverse[0][1], inverse[0][2], -translation.x],
+            [inverse[1][0], inverse[1][1], inverse[1][2], -translation.y],
+            [inverse[2][0], inverse[2][1], inverse[2][2], -translation.z],
+            [zero, zero, zero, one],
         ];
-        Matrix4::<u32> { values }
+        Some(Self { values })
     }
-    /// Returns a 4x4 translation matrix from the given vector
-    pub fn translation_matrix<V: ToVector3<u32>>(vector: V) -> Self {
-        let mut matrix = Self::identity_matrix();
-        let vector = vector.to_vec_3();
-        for i in 0..3 {
-            matrix[[3, i]] = vector[i];
+    /// Splits an affine transformation matrix into translation, rotation, shear and scale
+    ///
+    /// The columns of the upper left 3x3 part are orthogonalized with Gram-Schmidt, so shear
+    /// that the matrix was built with ends up in `shear` instead of distorting the rotation.
+    /// A negative determinant is represented by a negative x scale.
+    ///
+    /// Returns `None` if the bottom row is not `[0, 0, 0, 1]` or if any scale is zero
+    pub fn decompose(&self) -> Option<TransformParts<T>> {
+        let a = self.values;
+        let (zero, one) = (T::zero(), T::one());
+        if a[3] != [zero, zero, zero, one] {
+            return None;
         }
-        matrix
+
+        let mut columns = [
+            Vector3::new(a[0][0], a[1][0], a[2][0]),
+            Vector3::new(a[0][1], a[1][1], a[2][1]),
+            Vector3::new(a[0][2], a[1][2], a[2][2]),
+        ];
+        let largest = columns.iter().fold(zero, |largest, column| largest.max(column.magnitude()));
+        let tolerance = largest * T::epsilon() * (one + one + one);
+
+        let mut scale = Vector3::zero();
+        let mut shear = Vector3::zero();
+
+        scale.x = columns[0].magnitude();
+        if scale.x <= tolerance {
+            return None;
+        }
+        columns[0] /= scale.x;
+
+        shear.x = columns[0].dot(columns[1]);
+        columns[1] -= columns[0] * shear.x;
+        scale.y = columns[1].magnitude();
+        if scale.y <= tolerance {
+            return None;
+        }
+        columns[1] /= scale.y;
+
+        shear.y = columns[0].dot(columns[2]);
+        shear.z = columns[1].dot(columns[2]);
+        columns[2] = columns[2] - columns[0] * shear.y - columns[1] * shear.z;
+        scale.z = columns[2].magnitude();
+        if scale.z <= tolerance {
+            return None;
+        }
+        columns[2] /= scale.z;
+        shear = Vector3::new(shear.x / scale.y, shear.y / scale.z, shear.z / scale.z);
+
+        // Mirroring the x axis makes the rotation proper, the shear along x changes sign with it
+        if columns[0].triple_product(columns[1], columns[2]) < zero {
+            columns[0] = -columns[0];
+            scale.x = -scale.x;
+            shear.x = -shear.x;
+            shear.y = -shear.y;
+        }
+
+        let rotation = Matrix3::new([
+            [columns[0].x, columns[1].x, columns[2].x],
+            [columns[0].y, columns[1].y, columns[2].y],
+            [columns[0].z, columns[1].z, columns[2].z],
+        ]);
+
+        Some(TransformParts {
+            translation: Vector3::new(a[0][3], a[1][3], a[2][3]),
+            rotation,
+            quaternion: Quaternion::from_matrix3(rotation),
+            scale,
+            shear,
+        })
+    }
+    /// Returns the transformation matrix `translation * rotation * scale`,
+    /// the same as building it with `translate`, `rotate` and `scale`
+    ///
+    /// rotation: normalized quaternion
+    pub fn from_trs(translation: Vector3<T>, rotation: Quaternion<T>, scale: Vector3<T>) -> Self {
+        let r = rotation.to_matrix3().values;
+        let zero = T::zero();
+
+        let values = [
+            [r[0][0] * scale.x, r[0][1] * scale.y, r[0][2] * scale.z, translation.x],
+            [r[1][0] * scale.x, r[1][1] * scale.y, r[1][2] * scale.z, translation.y],
+            [r[2][0] * scale.x, r[2][1] * scale.y, r[2][2] * scale.z, translation.z],
+            [zero, zero, zero, T::one()],
+        ];
+        Self { values }
     }
 }
-impl Matrix4<f32> {
+impl<T> TransformParts<T>
+    where T: Float
+{
+    /// Returns the matrix the parts were decomposed from, including the shear
+    pub fn to_matrix(&self) -> Matrix4<T> {
+        let (zero, one) = (T::zero(), T::one());
+        let shear = Matrix4::new([
+            [one, self.shear.x, self.shear.y, zero],
+            [zero, one, self.shear.z, zero],
+            [zero, zero, one, zero],
+            [zero, zero, zero, one],
+        ]);
+        Matrix4::from_trs(self.translation, self.quaternion, Vector3::new(one, one, one)) * shear
+            * Matrix4::scaling_matrix(self.scale)
+    }
+}
+impl<T> Matrix4<T>
+    where T: Num + Copy
+{
     /// Returns a 4x4 identity matrix
-    pub fn identity_matrix() -> Matrix4<f32> {
-        let values: [[f32; 4]; 4] = [
-            [1.0, 0.0, 0.0, 0.0], 
-            [0.0, 1.0, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0]
-        ];
-        Matrix4::<f32> { values }
+    pub fn identity_matrix() -> Self {
+        Self::identity()
     }
     /// Returns a 4x4 translation matrix from the given vector
-    pub fn translation_matrix<V: ToVector3<f32>>(vector: V) -> Self {
+    ///
+    /// The translation is stored in the last column, `values[i][3]`
+    pub fn translation_matrix<V: ToVector3<T>>(vector: V) -> Self {
         let mut matrix = Self::identity_matrix();
         let vector = vector.to_vec_3();
         for i in 0..3 {
-            matrix[[3, i]] = vector[i];
+            matrix[[i, 3]] = vector[i];
         }
         matrix
     }
     /// Returns self multiplied by a transformation matrix from the given vector
     pub fn translate<V>(self, vector: V) -> Self
-        where V: Vector<f32> + ToVector3<f32>
+        where V: Vector<T> + ToVector3<T>
     {
         self * Self::translation_matrix(vector)
     }
-    /// Returns a 4x4 rotation matrix
-    /// 
-    /// axis: axis around witch the rotation takes place
-    /// degrees: degrees for rotation in radians
-    pub fn rotation_matrix(degrees: f32, u: Vector3<f32>) -> Self {
-        let (cos, sin) = (degrees.cos(), degrees.sin());
-        
-        let mut values = [
-            [cos + u.x.powi(2) * (1.0 - cos), u.x * u.y * (1.0 - cos) - u.z * sin, u.x * u.z * (1.0 - cos) + u.y * sin, 0.0],
-            [u.y * u.x * (1.0 - cos) + u.z * sin, cos + u.y.powi(2) * (1.0 - cos), u.y * u.z * (1.0 - cos) - u.x * sin, 0.0],
-            [u.z * u.x * (1.0 - cos) - u.y * sin, u.z * u.y * (1.0 - cos) + u.x * sin, cos + u.z.powi(2) * (1.0 - cos), 0.0],
-            [0.0, 0.0, 0.0, 1.0]
-        ];
-
-        for row in values.iter_mut() {
-            for element in row.iter_mut() {
-                if *element < 0.01 {
-                    *element = 0.0;
-                }
-                else if *element > 0.99 {
-                    *element = 1.0;
-                } 
-            }
-        }
-
-        Self { values }
-    }
-    /// Returns self multiplied by a rotation vector with a rotation of the given angle around the given vector.
-    /// 
-    /// axis: axis around witch the rotation takes place
-    /// degrees: degrees for rotation in radians
-    pub fn rotate(self, angle: f32, revultion_vector: Vector3<f32>) -> Self {
-        self * Self::rotation_matrix(angle, revultion_vector)
-    }
-    pub fn scaling_matrix<V: ToVector3<f32>>(vector: V) -> Self {
+    pub fn scaling_matrix<V: ToVector3<T>>(vector: V) -> Self {
         let vector = vector.to_vec_3();
         let mut output = Self::identity_matrix();
 
@@ -145,69 +272,58 @@ impl Matrix4<f32> {
         }
         output
     }
-    pub fn scale<V: ToVector3<f32>>(self, vector: V) -> Self {
+    pub fn scale<V: ToVector3<T>>(self, vector: V) -> Self {
         self * Self::scaling_matrix(vector)
     }
-}
-
-impl<T> Matrix for Matrix4<T>
-    where T: Num + Copy
-{
-    const SIZE: [usize; 2] = [4, 4];
-}
-
-impl<T> Add for Matrix4<T>
-    where T: Num + Copy
-{
-    type Output = Self;
-
-    fn add(self, other: Self) -> Self {
-        let (values1, values2) = (self.values, other.values);
-        let result: [[T; 4]; 4] = [
-            [values1[0][0] + values2[0][0], values1[0][1] + values2[0][1], values1[0][2] + values2[0][2], values1[0][3] + values2[0][3]],
-            [values1[1][0] + values2[1][0], values1[1][1] + values2[1][1], values1[1][2] + values2[1][2], values1[1][3] + values2[1][3]],
-            [values1[2][0] + values2[2][0], values1[2][1] + values2[2][1], values1[2][2] + values2[2][2], values1[2][3] + values2[2][3]],
-            [values1[3][0] + values2[3][0], values1[3][1] + values2[3][1], values1[3][2] + values2[3][2], values1[3][3] + values2[3][3]],
-        ];
-
-        Matrix4 { values: result }
+    /// Returns the point transformed by the matrix
+    ///
+    /// The point is extended with a `w` of one, so translations apply to it.
+    /// The result is divided by its `w`, which makes perspective projections work as expected.
+    ///
+    /// Returns `None` if the resulting `w` is zero, like for a point on the camera plane
+    /// under a perspective projection, see `Point3::from_homogeneous`
+    pub fn transform_point(&self, point: Point3<T>) -> Option<Point3<T>> {
+        Point3::from_homogeneous(*self * point.to_homogeneous())
+    }
+    /// Returns the direction transformed by the matrix
+    ///
+    /// The vector is extended with a `w` of zero, so translations do not apply to it
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        let result = *self * vector.to_vec_4();
+        Vector3::new(result.x, result.y, result.z)
     }
 }
-impl<T> Sub for Matrix4<T>
-    where T: Num + Copy
+impl<T> Matrix4<T>
+    where T: Float
 {
-    type Output = Self;
+    /// Returns a 4x4 rotation matrix
+    /// 
+    /// angle: angle for rotation, either `Rad` or `Deg`
+    /// axis: unit length axis around witch the rotation takes place
+    pub fn rotation_matrix<A: Into<Rad<T>>>(angle: A, axis: Unit<Vector3<T>>) -> Self {
+        let u = *axis;
+        let (sin, cos) = angle.into().sin_cos();
+        let t = T::one() - cos;
+        let (zero, one) = (T::zero(), T::one());
 
-    fn sub(self, other: Self) -> Self {
-        let (values1, values2) = (self.values, other.values);
-        let result: [[T; 4]; 4] = [
-            [values1[0][0] - values2[0][0], values1[0][1] - values2[0][1], values1[0][2] - values2[0][2], values1[0][3] - values2[0][3]],
-            [values1[1][0] - values2[1][0], values1[1][1] - values2[1][1], values1[1][2] - values2[1][2], values1[1][3] - values2[1][3]],
-            [values1[2][0] - values2[2][0], values1[2][1] - values2[2][1], values1[2][2] - values2[2][2], values1[2][3] - values2[2][3]],
-            [values1[3][0] - values2[3][0], values1[3][1] - values2[3][1], values1[3][2] - values2[3][2], values1[3][3] - values2[3][3]],
+        let values = [
+            [cos + u.x.powi(2) * t, u.x * u.y * t - u.z * sin, u.x * u.z * t + u.y * sin, zero],
+            [u.y * u.x * t + u.z * sin, cos + u.y.powi(2) * t, u.y * u.z * t - u.x * sin, zero],
+            [u.z * u.x * t - u.y * sin, u.z * u.y * t + u.x * sin, cos + u.z.powi(2) * t, zero],
+            [zero, zero, zero, one]
         ];
 
-        Matrix4 { values: result }
+        Self { values }
     }
-}
-impl<T> Mul<Matrix4<T>> for Matrix4<T>
-    where T: Num + Copy
-{
-    type Output = Matrix4<T>;
-
-    fn mul(self, other: Matrix4<T>) -> Matrix4<T> {
-       let rows1 = self.as_vectors_rows();
-       let collumns2 = other.as_vectors_collumns();
-       
-       let result: [[T; 4]; 4] = [
-           [(rows1[0] * collumns2[0]).sum(), (rows1[0] * collumns2[1]).sum(), (rows1[0] * collumns2[2]).sum(), (rows1[0] * collumns2[3]).sum()],
-           [(rows1[1] * collumns2[0]).sum(), (rows1[1] * collumns2[1]).sum(), (rows1[1] * collumns2[2]).sum(), (rows1[1] * collumns2[3]).sum()],
-           [(rows1[2] * collumns2[0]).sum(), (rows1[2] * collumns2[1]).sum(), (rows1[2] * collumns2[2]).sum(), (rows1[2] * collumns2[3]).sum()],
-           [(rows1[3] * collumns2[0]).sum(), (rows1[3] * collumns2[1]).sum(), (rows1[3] * collumns2[2]).sum(), (rows1[3] * collumns2[3]).sum()],
-       ];
-       Matrix4 { values: result }
+    /// Returns self multiplied by a rotation vector with a rotation of the given angle around the given vector.
+    /// 
+    /// angle: angle for rotation, either `Rad` or `Deg`
+    /// revultion_vector: unit length axis around witch the rotation takes place
+    pub fn rotate<A: Into<Rad<T>>>(self, angle: A, revultion_vector: Unit<Vector3<T>>) -> Self {
+        self * Self::rotation_matrix(angle, revultion_vector)
     }
 }
+
 impl<T> Mul<Vector4<T>> for Matrix4<T>
 where
     T: Num + Copy
@@ -215,70 +331,18 @@ where
     type Output = Vector4<T>;
 
     fn mul(self, other: Vector4<T>) -> Vector4<T> {
-        let rows = self.as_vectors_rows();
-        let result: Vector4<T> = Vector4::new(
-            (rows[0] * other).sum(),
-            (rows[1] * other).sum(),
-            (rows[2] * other).sum(),
-            (rows[3] * other).sum(),
-        );
-        result
-    }
-}
-
-impl<T> Index<[usize; 2]> for Matrix4<T>
-    where T: Num + Copy
-{
-    type Output = T;
-
-    fn index(&self, index: [usize; 2]) -> &Self::Output {
-        &self.values[index[1]][index[0]]
-    }
-}
-impl<T> IndexMut<[usize; 2]> for Matrix4<T>
-    where T: Num + Copy
-{
-    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
-        &mut self.values[index[1]][index[0]]
+        (self * VectorN::from(other)).into()
     }
 }
 
-impl<T> Display for Matrix4<T>
+impl<T> Mul<Point3<T>> for Matrix4<T>
 where
-    T: Num + Copy + Display
+    T: Num + Copy
 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let collumns = self.as_vectors_collumns();
-        let mut des_lenghts = [0; 4];
-
-        for i in 0..collumns.len() {
-            let mut longest = 0usize;
+    type Output = Option<Point3<T>>;
 
-            for element in collumns[i] {
-                let len = element.to_string().len();
-                if len > longest {
-                    longest = len;
-                }
-            }
-            des_lenghts[i] = longest;
-        }
-        let rows = self.as_vectors_rows();
-        let mut rows_strings = vec![String::new(); 4];
-
-        for i in 0..rows.len() {
-            for j in 0..rows[i].len() {
-                rows_strings[i].push_str(&format!("{} ", rows[i][j].to_string().pad_c(des_lenghts[j])))
-            }
-            rows_strings[i] = String::from(rows_strings[i].trim());
-        }
-
-        write!(
-            f,
-            "\n⎡{}⎤\n⎢{}⎥\n⎢{}⎥\n⎣{}⎦\n",
-            rows_strings[0],
-            rows_strings[1],
-            rows_strings[2],
-            rows_strings[3],
-        )
+    /// Same as `transform_point`
+    fn mul(self, other: Point3<T>) -> Option<Point3<T>> {
+        self.transform_point(other)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,339 @@
+use super::Matrix;
+use crate::vector::VectorN;
+use crate::utils::Pad;
+
+use num_traits::{Num, One, Zero};
+use std::ops::{Add, Mul, Sub, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign, Index, IndexMut};
+use std::fmt::{Display, Formatter};
+
+use super::MatrixMN;
+
+impl<T, const R: usize, const C: usize> MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    pub fn new(values: [[T; C]; R]) -> Self {
+        Self { values }
+    }
+    /// Returns a matrix with all elements set to zero
+    pub fn zero() -> Self {
+        Self { values: [[T::zero(); C]; R] }
+    }
+    /// Returns a matrix with all elements set to the given value
+    pub fn splat(value: T) -> Self {
+        Self { values: [[value; C]; R] }
+    }
+    /// Returns a matrix with the given rows
+    ///
+    /// Takes both `VectorN` and the vector type of the same size, like `Vector3` for a `Matrix3`
+    pub fn from_rows<V: Into<VectorN<T, C>>>(rows: [V; R]) -> Self {
+        Self { values: rows.map(|row| row.into().values) }
+    }
+    /// Returns a matrix with the given columns
+    ///
+    /// Takes both `VectorN` and the vector type of the same size, like `Vector3` for a `Matrix3`
+    pub fn from_cols<V: Into<VectorN<T, R>>>(columns: [V; C]) -> Self {
+        let columns: [VectorN<T, R>; C] = columns.map(Into::into);
+        let mut matrix = Self::zero();
+        for (j, column) in columns.iter().enumerate() {
+            matrix.set_col(j, *column);
+        }
+        matrix
+    }
+    /// Returns the element at the given row and column, or `None` if it is out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<T> {
+        self.values.get(row)?.get(column).copied()
+    }
+    /// Returns the given row as a vector
+    pub fn row(&self, row: usize) -> VectorN<T, C> {
+        VectorN::new(self.values[row])
+    }
+    /// Returns the given column as a vector
+    pub fn col(&self, column: usize) -> VectorN<T, R> {
+        let mut values = [T::zero(); R];
+        for (value, row) in values.iter_mut().zip(self.values.iter()) {
+            *value = row[column];
+        }
+        VectorN::new(values)
+    }
+    /// Replaces the given row
+    pub fn set_row<V: Into<VectorN<T, C>>>(&mut self, row: usize, vector: V) {
+        self.values[row] = vector.into().values;
+    }
+    /// Replaces the given column
+    pub fn set_col<V: Into<VectorN<T, R>>>(&mut self, column: usize, vector: V) {
+        for (row, value) in self.values.iter_mut().zip(vector.into()) {
+            row[column] = value;
+        }
+    }
+    /// Same as `row`
+    pub fn row_vector(&self, row: usize) -> VectorN<T, C> {
+        self.row(row)
+    }
+    /// Same as `col`
+    pub fn column_vector(&self, column: usize) -> VectorN<T, R> {
+        self.col(column)
+    }
+    /// Returns the elements one row after another, without copying
+    pub fn as_row_major_slice(&self) -> &[T] {
+        self.values.as_flattened()
+    }
+    /// Returns the elements as an array of columns, the column-major order of GPU APIs like OpenGL
+    ///
+    /// The matrix is stored row-major, so the elements are copied, `as_flattened` gives a flat slice
+    pub fn to_cols_array(&self) -> [[T; R]; C] {
+        self.transpose().values
+    }
+    /// Returns the transpose of the matrix
+    pub fn transpose(&self) -> MatrixMN<T, C, R> {
+        let mut values = [[T::zero(); R]; C];
+        for (i, row) in self.values.iter().enumerate() {
+            for (j, element) in row.iter().enumerate() {
+                values[j][i] = *element;
+            }
+        }
+        MatrixMN { values }
+    }
+    /// Returns the matrix with `f` applied to every element
+    fn map<F: Fn(T) -> T>(self, f: F) -> Self {
+        let mut values = self.values;
+        for element in values.iter_mut().flatten() {
+            *element = f(*element);
+        }
+        Self { values }
+    }
+}
+
+impl<T, const N: usize> MatrixMN<T, N, N>
+    where T: Num + Copy
+{
+    /// Returns the identity matrix
+    pub fn identity() -> Self {
+        let mut values = [[T::zero(); N]; N];
+        for (i, row) in values.iter_mut().enumerate() {
+            row[i] = T::one();
+        }
+        Self { values }
+    }
+    /// Returns a matrix with the given values on the diagonal and zeros elsewhere
+    ///
+    /// Takes both `VectorN` and the vector type of the same size, like `Vector3` for a `Matrix3`
+    pub fn from_diagonal<V: Into<VectorN<T, N>>>(diagonal: V) -> Self {
+        let diagonal = diagonal.into();
+        let mut values = [[T::zero(); N]; N];
+        for (i, row) in values.iter_mut().enumerate() {
+            row[i] = diagonal.values[i];
+        }
+        Self { values }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Zero for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    fn zero() -> Self {
+        Self::zero()
+    }
+    fn is_zero(&self) -> bool {
+        self.values.iter().flatten().all(|value| value.is_zero())
+    }
+}
+impl<T, const N: usize> One for MatrixMN<T, N, N>
+    where T: Num + Copy
+{
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Matrix for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    const SIZE: [usize; 2] = [R, C];
+}
+
+impl<T, const R: usize, const C: usize> Add for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut values = self.values;
+        for (row, other_row) in values.iter_mut().zip(other.values.iter()) {
+            for (element, other_element) in row.iter_mut().zip(other_row.iter()) {
+                *element = *element + *other_element;
+            }
+        }
+        Self { values }
+    }
+}
+impl<T, const R: usize, const C: usize> Sub for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let mut values = self.values;
+        for (row, other_row) in values.iter_mut().zip(other.values.iter()) {
+            for (element, other_element) in row.iter_mut().zip(other_row.iter()) {
+                *element = *element - *other_element;
+            }
+        }
+        Self { values }
+    }
+}
+impl<T, const R: usize, const C: usize, const K: usize> Mul<MatrixMN<T, C, K>> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    type Output = MatrixMN<T, R, K>;
+
+    fn mul(self, other: MatrixMN<T, C, K>) -> MatrixMN<T, R, K> {
+        let mut values = [[T::zero(); K]; R];
+        for (i, row) in values.iter_mut().enumerate() {
+            for (j, element) in row.iter_mut().enumerate() {
+                for k in 0..C {
+                    *element = *element + self.values[i][k] * other.values[k][j];
+                }
+            }
+        }
+        MatrixMN { values }
+    }
+}
+impl<T, const R: usize, const C: usize> Mul<VectorN<T, C>> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    type Output = VectorN<T, R>;
+
+    fn mul(self, other: VectorN<T, C>) -> VectorN<T, R> {
+        let mut values = [T::zero(); R];
+        for (value, row) in values.iter_mut().zip(self.values.iter()) {
+            for (element, other_element) in row.iter().zip(other.values.iter()) {
+                *value = *value + *element * *other_element;
+            }
+        }
+        VectorN::new(values)
+    }
+}
+impl<T, const R: usize, const C: usize> Mul<T> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        self.map(|element| element * scalar)
+    }
+}
+impl<T, const R: usize, const C: usize> Div<T> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+        self.map(|element| element / scalar)
+    }
+}
+impl<T, const R: usize, const C: usize> Neg for MatrixMN<T, R, C>
+    where T: Num + Copy + Neg<Output = T>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|element| -element)
+    }
+}
+
+impl<T, const R: usize, const C: usize> AddAssign for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+impl<T, const R: usize, const C: usize> SubAssign for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+impl<T, const R: usize, const C: usize> MulAssign<MatrixMN<T, C, C>> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    fn mul_assign(&mut self, other: MatrixMN<T, C, C>) {
+        *self = *self * other;
+    }
+}
+impl<T, const R: usize, const C: usize> MulAssign<T> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
+    }
+}
+impl<T, const R: usize, const C: usize> DivAssign<T> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    fn div_assign(&mut self, scalar: T) {
+        *self = *self / scalar;
+    }
+}
+
+impl_left_scalar_mul!(MatrixMN<R, C>);
+
+impl<T, const R: usize, const C: usize> Index<[usize; 2]> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    type Output = T;
+
+    /// Takes `[row, column]`, like `get`
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        &self.values[index[0]][index[1]]
+    }
+}
+impl<T, const R: usize, const C: usize> IndexMut<[usize; 2]> for MatrixMN<T, R, C>
+    where T: Num + Copy
+{
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        &mut self.values[index[0]][index[1]]
+    }
+}
+
+impl<T, const R: usize, const C: usize> Display for MatrixMN<T, R, C>
+where
+    T: Num + Copy + Display
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut des_lenghts = [0; C];
+
+        for row in self.values.iter() {
+            for (j, element) in row.iter().enumerate() {
+                let len = element.to_string().len();
+                if len > des_lenghts[j] {
+                    des_lenghts[j] = len;
+                }
+            }
+        }
+
+        writeln!(f)?;
+        for (i, row) in self.values.iter().enumerate() {
+            let mut row_string = String::new();
+            for (j, element) in row.iter().enumerate() {
+                row_string.push_str(&format!("{} ", element.to_string().pad_c(des_lenghts[j])))
+            }
+
+            let (left, right) = if R == 1 {
+                ('[', ']')
+            }
+            else if i == 0 {
+                ('⎡', '⎤')
+            }
+            else if i == R - 1 {
+                ('⎣', '⎦')
+            }
+            else {
+                ('⎢', '⎥')
+            };
+            writeln!(f, "{}{}{}", left, row_string.trim(), right)?;
+        }
+        Ok(())
+    }
+}
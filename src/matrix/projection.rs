@@ -0,0 +1,119 @@
+use crate::angles::Rad;
+
+use num_traits::Float;
+
+use super::Matrix4;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The range that depth is mapped to in normalized device coordinates.
+pub enum DepthRange {
+    /// Depth from -1 to 1, as used by OpenGL
+    NegativeOneToOne,
+    /// Depth from 0 to 1, as used by Vulkan, DirectX and Metal
+    ZeroToOne,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The clip space conventions of a graphics API, used by the projection matrices.
+pub struct ClipSpace {
+    pub depth: DepthRange,
+    /// Flips the y axis, for APIs where y points down in normalized device coordinates
+    pub flip_y: bool,
+}
+
+impl ClipSpace {
+    pub const OPENGL: ClipSpace = ClipSpace { depth: DepthRange::NegativeOneToOne, flip_y: false };
+    pub const VULKAN: ClipSpace = ClipSpace { depth: DepthRange::ZeroToOne, flip_y: true };
+    pub const DIRECTX: ClipSpace = ClipSpace { depth: DepthRange::ZeroToOne, flip_y: false };
+
+    /// Returns the depth that the near and far planes are mapped to
+    fn depth_bounds<T: Float>(&self) -> (T, T) {
+        match self.depth {
+            DepthRange::NegativeOneToOne => (-T::one(), T::one()),
+            DepthRange::ZeroToOne => (T::zero(), T::one()),
+        }
+    }
+    fn y_sign<T: Float>(&self) -> T {
+        if self.flip_y { -T::one() } else { T::one() }
+    }
+}
+
+/// Projection matrices
+///
+/// All projections expect a right handed view space, with the camera looking down the negative z axis
+/// and near and far given as positive distances from the camera.
+impl<T> Matrix4<T>
+    where T: Float
+{
+    /// Returns a perspective projection matrix
+    ///
+    /// fov_y: vertical field of view, either `Rad` or `Deg`
+    /// aspect: width divided by height of the viewport
+    pub fn perspective<A: Into<Rad<T>>>(fov_y: A, aspect: T, near: T, far: T, clip: ClipSpace) -> Self {
+        let (depth_near, depth_far) = clip.depth_bounds();
+        Self::perspective_mapped(fov_y.into(), aspect, near, Some(far), depth_near, depth_far, clip)
+    }
+    /// Returns a perspective projection matrix with the far plane at infinity
+    pub fn perspective_infinite<A: Into<Rad<T>>>(fov_y: A, aspect: T, near: T, clip: ClipSpace) -> Self {
+        let (depth_near, depth_far) = clip.depth_bounds();
+        Self::perspective_mapped(fov_y.into(), aspect, near, None, depth_near, depth_far, clip)
+    }
+    /// Returns a perspective projection matrix mapping the near plane to the largest
+    /// and the far plane to the smallest depth, for better depth precision
+    pub fn perspective_reversed_z<A: Into<Rad<T>>>(fov_y: A, aspect: T, near: T, far: T, clip: ClipSpace) -> Self {
+        let (depth_near, depth_far) = clip.depth_bounds();
+        Self::perspective_mapped(fov_y.into(), aspect, near, Some(far), depth_far, depth_near, clip)
+    }
+    /// Returns a reversed z perspective projection matrix with the far plane at infinity
+    pub fn perspective_infinite_reversed_z<A: Into<Rad<T>>>(fov_y: A, aspect: T, near: T, clip: ClipSpace) -> Self {
+        let (depth_near, depth_far) = clip.depth_bounds();
+        Self::perspective_mapped(fov_y.into(), aspect, near, None, depth_far, depth_near, clip)
+    }
+    /// Returns an orthographic projection matrix mapping the given box to the clip space
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T, clip: ClipSpace) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let two = one + one;
+        let (depth_near, depth_far): (T, T) = clip.depth_bounds();
+        let y_sign: T = clip.y_sign();
+
+        let a = (depth_near - depth_far) / (far - near);
+        let b = depth_near + a * near;
+        let values = [
+            [two / (right - left), zero, zero, -(right + left) / (right - left)],
+            [zero, y_sign * two / (top - bottom), zero, -y_sign * (top + bottom) / (top - bottom)],
+            [zero, zero, a, b],
+            [zero, zero, zero, one],
+        ];
+        Self { values }
+    }
+    /// Returns a perspective matrix mapping the near plane to `depth_near` and the far plane,
+    /// or infinity if there is none, to `depth_far`
+    fn perspective_mapped(
+        fov_y: Rad<T>,
+        aspect: T,
+        near: T,
+        far: Option<T>,
+        depth_near: T,
+        depth_far: T,
+        clip: ClipSpace,
+    ) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let focal_length = one / (fov_y / (one + one)).tan();
+
+        // Depth is (a * z + b) / -z, solved for the near and far plane
+        let (a, b) = match far {
+            Some(far) => {
+                let a = (depth_near * near - depth_far * far) / (far - near);
+                (a, depth_near * near + a * near)
+            }
+            None => (-depth_far, (depth_near - depth_far) * near),
+        };
+        let values = [
+            [focal_length / aspect, zero, zero, zero],
+            [zero, clip.y_sign::<T>() * focal_length, zero, zero],
+            [zero, zero, a, b],
+            [zero, zero, -one, zero],
+        ];
+        Self { values }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::vector::Vector3;
+
+use num_traits::Float;
+
+use super::Matrix4;
+
+/// View matrices
+///
+/// Right handed view matrices make the camera look down the negative z axis,
+/// left handed ones down the positive z axis. The up vector does not have to be
+/// perpendicular to the view direction, but can not be parallel to it.
+impl<T> Matrix4<T>
+    where T: Float
+{
+    /// Returns a right handed view matrix for a camera at `eye` looking at `target`
+    pub fn look_at_rh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        Self::look_to_rh(eye, target - eye, up)
+    }
+    /// Returns a left handed view matrix for a camera at `eye` looking at `target`
+    pub fn look_at_lh(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        Self::look_to_lh(eye, target - eye, up)
+    }
+    /// Returns a right handed view matrix for a camera at `eye` looking in `direction`
+    pub fn look_to_rh(eye: Vector3<T>, direction: Vector3<T>, up: Vector3<T>) -> Self {
+        let forward = direction.normalized();
+        let side = forward.cross(up).normalized();
+        let up = side.cross(forward);
+
+        Self::view_matrix(eye, side, up, -forward)
+    }
+    /// Returns a left handed view matrix for a camera at `eye` looking in `direction`
+    pub fn look_to_lh(eye: Vector3<T>, direction: Vector3<T>, up: Vector3<T>) -> Self {
+        let forward = direction.normalized();
+        let side = up.cross(forward).normalized();
+        let up = forward.cross(side);
+
+        Self::view_matrix(eye, side, up, forward)
+    }
+    /// Returns a matrix with the given orthonormal axes as rows, moving `eye` to the origin
+    fn view_matrix(eye: Vector3<T>, x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let values = [
+            [x.x, x.y, x.z, -x.dot(eye)],
+            [y.x, y.y, y.z, -y.dot(eye)],
+            [z.x, z.y, z.z, -z.dot(eye)],
+            [zero, zero, zero, one],
+        ];
+        Self { values }
+    }
+}
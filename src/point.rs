@@ -0,0 +1,150 @@
+use crate::vector::{Vector3, Vector4};
+
+use num_traits::{Float, Num};
+use std::ops::{Add, AddAssign, Sub, SubAssign, Index, IndexMut};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A position in 3D space.
+///
+/// Unlike `Vector3`, which is a direction, a point is moved by translations.
+/// The difference of two points is a `Vector3`, and a point plus a `Vector3` is a point.
+pub struct Point3<T>
+    where T: Num + Copy
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T>
+    where T: Num + Copy
+{
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+    /// Returns the point at the origin
+    pub fn origin() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+    /// Returns the point at the given offset from the origin
+    pub fn from_vector(vector: Vector3<T>) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+    /// Returns the offset of the point from the origin
+    pub fn to_vector(self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+    /// Returns the point in homogeneous coordinates, with `w` set to one
+    pub fn to_homogeneous(self) -> Vector4<T> {
+        Vector4::new(self.x, self.y, self.z, T::one())
+    }
+    /// Returns the point from homogeneous coordinates, dividing by `w`
+    ///
+    /// Returns `None` if `w` is zero, as the vector is then a direction
+    pub fn from_homogeneous(vector: Vector4<T>) -> Option<Self> {
+        if vector.w == T::zero() {
+            return None;
+        }
+        Some(Self::new(vector.x / vector.w, vector.y / vector.w, vector.z / vector.w))
+    }
+    /// Returns the squared distance between the two points
+    pub fn distance_squared(&self, other: Self) -> T {
+        (*self - other).magnitude_squared()
+    }
+}
+impl<T> Point3<T>
+    where T: Float
+{
+    /// Returns the distance between the two points
+    pub fn distance(&self, other: Self) -> T {
+        (*self - other).magnitude()
+    }
+    /// Returns the point linearly interpolated between the two points, `t` of zero gives `self`
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self) * t
+    }
+}
+
+impl<T> Sub for Point3<T>
+    where T: Num + Copy
+{
+    type Output = Vector3<T>;
+
+    fn sub(self, other: Self) -> Vector3<T> {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+impl<T> Add<Vector3<T>> for Point3<T>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn add(self, other: Vector3<T>) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+impl<T> Sub<Vector3<T>> for Point3<T>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn sub(self, other: Vector3<T>) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+impl<T> AddAssign<Vector3<T>> for Point3<T>
+    where T: Num + Copy
+{
+    fn add_assign(&mut self, other: Vector3<T>) {
+        *self = *self + other;
+    }
+}
+impl<T> SubAssign<Vector3<T>> for Point3<T>
+    where T: Num + Copy
+{
+    fn sub_assign(&mut self, other: Vector3<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<[T; 3]> for Point3<T>
+    where T: Num + Copy
+{
+    fn from(values: [T; 3]) -> Self {
+        Self::new(values[0], values[1], values[2])
+    }
+}
+impl<T> From<Point3<T>> for [T; 3]
+    where T: Num + Copy
+{
+    fn from(point: Point3<T>) -> Self {
+        [point.x, point.y, point.z]
+    }
+}
+
+impl<T> Index<usize> for Point3<T>
+    where T: Num + Copy
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+impl<T> IndexMut<usize> for Point3<T>
+    where T: Num + Copy
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
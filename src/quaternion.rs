@@ -0,0 +1,248 @@
+use crate::angles::Rad;
+use crate::matrix::{Matrix3, Matrix4};
+use crate::vector::{Unit, Vector3};
+
+use num_traits::Float;
+use std::ops::{Add, Sub, Mul, Neg};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A quaternion `w + xi + yj + zk`, used to represent rotations.
+///
+/// Rotations follow the same convention as `Matrix3::rotation_matrix`,
+/// a positive angle rotates counterclockwise around the axis.
+pub struct Quaternion<T>
+    where T: Float
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T> Quaternion<T>
+    where T: Float
+{
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+    /// Returns the quaternion representing no rotation
+    pub fn identity() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero(), T::one())
+    }
+    /// Returns a quaternion representing a rotation of the given angle around the given axis
+    ///
+    /// axis: unit length axis around witch the rotation takes place
+    /// angle: angle for rotation, either `Rad` or `Deg`
+    pub fn from_axis_angle<A: Into<Rad<T>>>(axis: Unit<Vector3<T>>, angle: A) -> Self {
+        let (sin, cos) = (angle.into() / (T::one() + T::one())).sin_cos();
+
+        Self::new(axis.x * sin, axis.y * sin, axis.z * sin, cos)
+    }
+    /// Returns the vector part of the quaternion
+    pub fn vector(&self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+    pub fn dot(&self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+    pub fn magnitude(&self) -> T {
+        self.dot(*self).sqrt()
+    }
+    pub fn normalize(&mut self) {
+        let magnitude = self.magnitude();
+        self.x = self.x / magnitude;
+        self.y = self.y / magnitude;
+        self.z = self.z / magnitude;
+        self.w = self.w / magnitude;
+    }
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+    /// Returns the inverse of the quaternion
+    ///
+    /// Returns `None` for the zero quaternion
+    pub fn try_inverse(&self) -> Option<Self> {
+        let magnitude_squared = self.dot(*self);
+        if magnitude_squared == T::zero() {
+            return None;
+        }
+        let conjugate = self.conjugate();
+        Some(conjugate * (T::one() / magnitude_squared))
+    }
+    /// Returns the given vector rotated by the quaternion
+    ///
+    /// The quaternion is expected to be normalized
+    pub fn rotate_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        let two = T::one() + T::one();
+        let u = self.vector();
+        // t = 2 * (u x v), v' = v + w * t + u x t
+        let t = u.cross(vector) * two;
+        vector + t * self.w + u.cross(t)
+    }
+    /// Normalized linear interpolation between two rotations
+    ///
+    /// Always takes the shortest path, t is expected to be between 0 and 1
+    pub fn nlerp(self, other: Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() { -other } else { other };
+        let mut result = self * (T::one() - t) + other * t;
+        result.normalize();
+        result
+    }
+    /// Spherical linear interpolation between two rotations
+    ///
+    /// Interpolates with a constant angular velocity and always takes the shortest path,
+    /// t is expected to be between 0 and 1
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = other;
+        if cos < T::zero() {
+            cos = -cos;
+            other = -other;
+        }
+        // Nearly parallel rotations would divide by a sine close to zero
+        if cos > T::one() - T::epsilon().sqrt() {
+            return self.nlerp(other, t);
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((T::one() - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+        self * a + other * b
+    }
+    /// Returns the rotation matrix of a normalized quaternion
+    pub fn to_matrix3(&self) -> Matrix3<T> {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let one = T::one();
+        let two = one + one;
+
+        Matrix3::new([
+            [one - two * (y * y + z * z), two * (x * y - z * w), two * (x * z + y * w)],
+            [two * (x * y + z * w), one - two * (x * x + z * z), two * (y * z - x * w)],
+            [two * (x * z - y * w), two * (y * z + x * w), one - two * (x * x + y * y)],
+        ])
+    }
+    /// Returns the 4x4 rotation matrix of a normalized quaternion
+    pub fn to_matrix4(&self) -> Matrix4<T> {
+        let r = self.to_matrix3().values;
+        let (zero, one) = (T::zero(), T::one());
+
+        Matrix4::new([
+            [r[0][0], r[0][1], r[0][2], zero],
+            [r[1][0], r[1][1], r[1][2], zero],
+            [r[2][0], r[2][1], r[2][2], zero],
+            [zero, zero, zero, one],
+        ])
+    }
+    /// Creates a normalized quaternion from a rotation matrix
+    ///
+    /// The matrix is expected to be orthonormal with a determinant of one
+    pub fn from_matrix3(matrix: Matrix3<T>) -> Self {
+        let m = matrix.values;
+        let one = T::one();
+        let two = one + one;
+        let quarter = one / (two * two);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        // Picks the largest of w, x, y and z to divide by, to avoid losing precision
+        let mut result = if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Self::new(
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+                quarter * s,
+            )
+        }
+        else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+            Self::new(
+                quarter * s,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[2][1] - m[1][2]) / s,
+            )
+        }
+        else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+            Self::new(
+                (m[0][1] + m[1][0]) / s,
+                quarter * s,
+                (m[1][2] + m[2][1]) / s,
+                (m[0][2] - m[2][0]) / s,
+            )
+        }
+        else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+            Self::new(
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                quarter * s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        };
+        result.normalize();
+        result
+    }
+    /// Creates a normalized quaternion from the rotation part of a 4x4 matrix
+    pub fn from_matrix4(matrix: Matrix4<T>) -> Self {
+        let m = matrix.values;
+        Self::from_matrix3(Matrix3::new([
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]],
+        ]))
+    }
+}
+
+impl<T> Add for Quaternion<T>
+    where T: Float
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z, self.w + other.w)
+    }
+}
+impl<T> Sub for Quaternion<T>
+    where T: Float
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z, self.w - other.w)
+    }
+}
+impl<T> Neg for Quaternion<T>
+    where T: Float
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+impl<T> Mul<T> for Quaternion<T>
+    where T: Float
+{
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        Self::new(self.x * other, self.y * other, self.z * other, self.w * other)
+    }
+}
+impl<T> Mul<Quaternion<T>> for Quaternion<T>
+    where T: Float
+{
+    type Output = Self;
+
+    /// The Hamilton product, the resulting rotation applies `other` first and then `self`
+    fn mul(self, other: Quaternion<T>) -> Self {
+        Self::new(
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        )
+    }
+}
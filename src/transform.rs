@@ -0,0 +1,455 @@
+use crate::angles::Rad;
+use crate::matrix::{Matrix3, Matrix3x4, Matrix4};
+use crate::point::Point3;
+use crate::quaternion::Quaternion;
+use crate::vector::{ToVector2, Unit, Vector2, Vector3};
+
+use num_traits::Float;
+use std::ops::Mul;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A rigid transform, a rotation followed by a translation.
+///
+/// Equal to `Matrix4::translation_matrix(translation) * rotation.to_matrix4()`.
+/// Like matrices, `a * b` applies `b` first and then `a`.
+pub struct Isometry3<T>
+    where T: Float
+{
+    pub translation: Vector3<T>,
+    /// Normalized quaternion
+    pub rotation: Quaternion<T>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A rigid transform with a uniform scale, applied in the order scale, rotation and translation.
+///
+/// Equal to `Matrix4::from_trs(translation, rotation, Vector3::splat(scale))`.
+pub struct Similarity3<T>
+    where T: Float
+{
+    pub translation: Vector3<T>,
+    /// Normalized quaternion
+    pub rotation: Quaternion<T>,
+    pub scale: T,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A general affine transform, stored as the upper three rows of a `Matrix4`.
+///
+/// The left 3x3 part is the linear transform and the last column the translation.
+pub struct Affine3<T>
+    where T: Float
+{
+    pub matrix: Matrix3x4<T>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A 2D affine transform, stored as a homogeneous `Matrix3` with a bottom row of `[0, 0, 1]`.
+///
+/// Chaining works like `Matrix4`, each call multiplies from the right, so
+/// `Transform2D::identity().translate(t).rotate(a)` rotates points first and then translates them.
+pub struct Transform2D<T>
+    where T: Float
+{
+    pub matrix: Matrix3<T>,
+}
+
+/// Largest difference from the expected scale and shear when converting from a `Matrix4`
+fn tolerance<T: Float>() -> T {
+    T::epsilon().sqrt()
+}
+
+impl<T> Isometry3<T>
+    where T: Float
+{
+    pub fn new(translation: Vector3<T>, rotation: Quaternion<T>) -> Self {
+        Self { translation, rotation }
+    }
+    /// Returns the transform that changes nothing
+    pub fn identity() -> Self {
+        Self::new(Vector3::zero(), Quaternion::identity())
+    }
+    /// Returns a translation, like `Matrix4::translation_matrix`
+    pub fn from_translation(translation: Vector3<T>) -> Self {
+        Self::new(translation, Quaternion::identity())
+    }
+    /// Returns a rotation, like `Matrix4::rotation_matrix`
+    ///
+    /// angle: angle for rotation, either `Rad` or `Deg`
+    /// axis: unit length axis around witch the rotation takes place
+    pub fn from_rotation<A: Into<Rad<T>>>(angle: A, axis: Unit<Vector3<T>>) -> Self {
+        Self::new(Vector3::zero(), Quaternion::from_axis_angle(axis, angle))
+    }
+    /// Returns the point moved by the transform
+    pub fn transform_point(&self, point: Point3<T>) -> Point3<T> {
+        Point3::from_vector(self.rotation.rotate_vector(point.to_vector()) + self.translation)
+    }
+    /// Returns the direction rotated by the transform, the translation does not apply to it
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        self.rotation.rotate_vector(vector)
+    }
+    /// Returns the inverse transform, only a conjugate and a rotation of the translation
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        Self::new(-rotation.rotate_vector(self.translation), rotation)
+    }
+    /// Interpolates the translation linearly and the rotation with `slerp`
+    ///
+    /// t is expected to be between 0 and 1
+    pub fn interpolate(&self, other: Self, t: T) -> Self {
+        Self::new(self.translation.lerp(other.translation, t), self.rotation.slerp(other.rotation, t))
+    }
+    /// Returns the transform as a 4x4 matrix
+    pub fn to_matrix4(&self) -> Matrix4<T> {
+        Matrix4::from_trs(self.translation, self.rotation, Vector3::splat(T::one()))
+    }
+    /// Returns the rigid transform of a matrix
+    ///
+    /// Returns `None` if the matrix does not decompose or if it scales or shears
+    pub fn from_matrix4(matrix: Matrix4<T>) -> Option<Self> {
+        let similarity = Similarity3::from_matrix4(matrix)?;
+        if (similarity.scale - T::one()).abs() > tolerance() {
+            return None;
+        }
+        Some(Self::new(similarity.translation, similarity.rotation))
+    }
+}
+
+impl<T> Similarity3<T>
+    where T: Float
+{
+    pub fn new(translation: Vector3<T>, rotation: Quaternion<T>, scale: T) -> Self {
+        Self { translation, rotation, scale }
+    }
+    /// Returns the transform that changes nothing
+    pub fn identity() -> Self {
+        Self::new(Vector3::zero(), Quaternion::identity(), T::one())
+    }
+    /// Returns a uniform scaling, like `Matrix4::scaling_matrix` with equal elements
+    pub fn from_scale(scale: T) -> Self {
+        Self::new(Vector3::zero(), Quaternion::identity(), scale)
+    }
+    /// Returns the rotation and translation part
+    pub fn isometry(&self) -> Isometry3<T> {
+        Isometry3::new(self.translation, self.rotation)
+    }
+    /// Returns the point moved by the transform
+    pub fn transform_point(&self, point: Point3<T>) -> Point3<T> {
+        Point3::from_vector(self.transform_vector(point.to_vector()) + self.translation)
+    }
+    /// Returns the direction scaled and rotated by the transform, the translation does not apply to it
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        self.rotation.rotate_vector(vector * self.scale)
+    }
+    /// Returns the inverse transform
+    ///
+    /// Returns `None` if the scale is zero
+    pub fn try_inverse(&self) -> Option<Self> {
+        if self.scale == T::zero() {
+            return None;
+        }
+        let rotation = self.rotation.conjugate();
+        let scale = T::one() / self.scale;
+        Some(Self::new(-rotation.rotate_vector(self.translation) * scale, rotation, scale))
+    }
+    /// Interpolates the translation and scale linearly and the rotation with `slerp`
+    ///
+    /// t is expected to be between 0 and 1
+    pub fn interpolate(&self, other: Self, t: T) -> Self {
+        Self::new(
+            self.translation.lerp(other.translation, t),
+            self.rotation.slerp(other.rotation, t),
+            self.scale + (other.scale - self.scale) * t,
+        )
+    }
+    /// Returns the transform as a 4x4 matrix
+    pub fn to_matrix4(&self) -> Matrix4<T> {
+        Matrix4::from_trs(self.translation, self.rotation, Vector3::splat(self.scale))
+    }
+    /// Returns the similarity transform of a matrix
+    ///
+    /// Returns `None` if the matrix does not decompose, shears or does not scale uniformly.
+    /// Mirroring transforms are not similarities in this sense and also return `None`.
+    pub fn from_matrix4(matrix: Matrix4<T>) -> Option<Self> {
+        let parts = matrix.decompose()?;
+        let scale = parts.scale.x;
+        let tolerance = tolerance::<T>();
+        if parts.shear.magnitude() > tolerance
+            || (parts.scale.y - scale).abs() > tolerance * scale.abs()
+            || (parts.scale.z - scale).abs() > tolerance * scale.abs()
+        {
+            return None;
+        }
+        Some(Self::new(parts.translation, parts.quaternion, scale))
+    }
+}
+
+impl<T> Affine3<T>
+    where T: Float
+{
+    pub fn new(matrix: Matrix3x4<T>) -> Self {
+        Self { matrix }
+    }
+    /// Returns the transform that changes nothing
+    pub fn identity() -> Self {
+        Self::from_parts(Matrix3::identity(), Vector3::zero())
+    }
+    /// Returns the transform that applies `linear` and then translates
+    pub fn from_parts(linear: Matrix3<T>, translation: Vector3<T>) -> Self {
+        let (l, t) = (linear.values, translation);
+        Self::new(Matrix3x4::new([
+            [l[0][0], l[0][1], l[0][2], t.x],
+            [l[1][0], l[1][1], l[1][2], t.y],
+            [l[2][0], l[2][1], l[2][2], t.z],
+        ]))
+    }
+    /// Returns a scaling along each axis, like `Matrix4::scaling_matrix`
+    pub fn from_scale(scale: Vector3<T>) -> Self {
+        Self::from_parts(Matrix3::from_diagonal(scale), Vector3::zero())
+    }
+    /// Returns the linear part of the transform
+    pub fn linear(&self) -> Matrix3<T> {
+        let m = self.matrix.values;
+        Matrix3::new([
+            [m[0][0], m[0][1], m[0][2]],
+            [m[1][0], m[1][1], m[1][2]],
+            [m[2][0], m[2][1], m[2][2]],
+        ])
+    }
+    /// Returns the translation part of the transform
+    pub fn translation(&self) -> Vector3<T> {
+        let m = self.matrix.values;
+        Vector3::new(m[0][3], m[1][3], m[2][3])
+    }
+    /// Returns the point moved by the transform
+    pub fn transform_point(&self, point: Point3<T>) -> Point3<T> {
+        Point3::from_vector(self.transform_vector(point.to_vector()) + self.translation())
+    }
+    /// Returns the direction transformed by the linear part, the translation does not apply to it
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        self.linear() * vector
+    }
+    /// Returns the inverse transform, only the 3x3 linear part has to be inverted
+    ///
+    /// Returns `None` if the linear part is singular
+    pub fn try_inverse(&self) -> Option<Self> {
+        let inverse = self.linear().try_inverse()?;
+        Some(Self::from_parts(inverse, -(inverse * self.translation())))
+    }
+    /// Interpolates the decomposed translation, rotation, shear and scale of the transforms
+    ///
+    /// The rotation is interpolated with `slerp` and the rest linearly, t is expected to be between 0 and 1.
+    /// Returns `None` if either transform does not decompose, see `Matrix4::decompose`
+    pub fn interpolate(&self, other: Self, t: T) -> Option<Self> {
+        let mut parts = self.to_matrix4().decompose()?;
+        let other = other.to_matrix4().decompose()?;
+
+        parts.translation = parts.translation.lerp(other.translation, t);
+        parts.quaternion = parts.quaternion.slerp(other.quaternion, t);
+        parts.scale = parts.scale.lerp(other.scale, t);
+        parts.shear = parts.shear.lerp(other.shear, t);
+        Self::from_matrix4(parts.to_matrix())
+    }
+    /// Returns the transform as a 4x4 matrix, with a bottom row of `[0, 0, 0, 1]`
+    pub fn to_matrix4(&self) -> Matrix4<T> {
+        let m = self.matrix.values;
+        let (zero, one) = (T::zero(), T::one());
+        Matrix4::new([m[0], m[1], m[2], [zero, zero, zero, one]])
+    }
+    /// Returns the affine transform of a matrix
+    ///
+    /// Returns `None` if the bottom row is not `[0, 0, 0, 1]`
+    pub fn from_matrix4(matrix: Matrix4<T>) -> Option<Self> {
+        let m = matrix.values;
+        let (zero, one) = (T::zero(), T::one());
+        if m[3] != [zero, zero, zero, one] {
+            return None;
+        }
+        Some(Self::new(Matrix3x4::new([m[0], m[1], m[2]])))
+    }
+}
+
+impl<T> Transform2D<T>
+    where T: Float
+{
+    /// Returns the transform that changes nothing
+    pub fn identity() -> Self {
+        Self { matrix: Matrix3::identity() }
+    }
+    /// Returns the transform of a homogeneous 2D matrix
+    ///
+    /// Returns `None` if the bottom row is not `[0, 0, 1]`
+    pub fn from_matrix3(matrix: Matrix3<T>) -> Option<Self> {
+        let (zero, one) = (T::zero(), T::one());
+        if matrix.values[2] != [zero, zero, one] {
+            return None;
+        }
+        Some(Self { matrix })
+    }
+    /// Returns self with a translation by the given vector applied to points first
+    pub fn translate<V: ToVector2<T>>(self, vector: V) -> Self {
+        self * Self { matrix: Matrix3::translation_2d(vector) }
+    }
+    /// Returns self with a counterclockwise rotation applied to points first
+    ///
+    /// angle: angle for rotation, either `Rad` or `Deg`
+    pub fn rotate<A: Into<Rad<T>>>(self, angle: A) -> Self {
+        self * Self { matrix: Matrix3::rotation_2d(angle) }
+    }
+    /// Returns self with a scaling along each axis applied to points first
+    pub fn scale<V: ToVector2<T>>(self, vector: V) -> Self {
+        self * Self { matrix: Matrix3::scaling_2d(vector) }
+    }
+    /// Returns self with a shear applied to points first, see `Matrix3::shear_2d`
+    pub fn shear<V: ToVector2<T>>(self, factors: V) -> Self {
+        self * Self { matrix: Matrix3::shear_2d(factors) }
+    }
+    /// Returns the translation part of the transform
+    pub fn translation(&self) -> Vector2<T> {
+        Vector2::new(self.matrix.values[0][2], self.matrix.values[1][2])
+    }
+    /// Returns the point moved by the transform
+    pub fn transform_point(&self, point: Vector2<T>) -> Vector2<T> {
+        self.matrix.transform_vector2(point) + self.translation()
+    }
+    /// Returns the direction transformed by the transform, the translation does not apply to it
+    pub fn transform_vector(&self, vector: Vector2<T>) -> Vector2<T> {
+        self.matrix.transform_vector2(vector)
+    }
+    /// Returns the inverse transform
+    ///
+    /// Returns `None` if the transform collapses the plane onto a line or a point
+    pub fn try_inverse(&self) -> Option<Self> {
+        Some(Self { matrix: self.matrix.affine_inverse_2d()? })
+    }
+}
+
+impl<T> Mul for Isometry3<T>
+    where T: Float
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.transform_point(Point3::from_vector(other.translation)).to_vector(),
+            self.rotation * other.rotation,
+        )
+    }
+}
+impl<T> Mul for Similarity3<T>
+    where T: Float
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.transform_point(Point3::from_vector(other.translation)).to_vector(),
+            self.rotation * other.rotation,
+            self.scale * other.scale,
+        )
+    }
+}
+impl<T> Mul for Affine3<T>
+    where T: Float
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::from_parts(
+            self.linear() * other.linear(),
+            self.transform_point(Point3::from_vector(other.translation())).to_vector(),
+        )
+    }
+}
+
+impl<T> Mul for Transform2D<T>
+    where T: Float
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self { matrix: self.matrix * other.matrix }
+    }
+}
+
+impl<T> Mul<Point3<T>> for Isometry3<T>
+    where T: Float
+{
+    type Output = Point3<T>;
+
+    /// Same as `transform_point`
+    fn mul(self, other: Point3<T>) -> Point3<T> {
+        self.transform_point(other)
+    }
+}
+impl<T> Mul<Point3<T>> for Similarity3<T>
+    where T: Float
+{
+    type Output = Point3<T>;
+
+    /// Same as `transform_point`
+    fn mul(self, other: Point3<T>) -> Point3<T> {
+        self.transform_point(other)
+    }
+}
+impl<T> Mul<Point3<T>> for Affine3<T>
+    where T: Float
+{
+    type Output = Point3<T>;
+
+    /// Same as `transform_point`
+    fn mul(self, other: Point3<T>) -> Point3<T> {
+        self.transform_point(other)
+    }
+}
+
+impl<T> From<Isometry3<T>> for Similarity3<T>
+    where T: Float
+{
+    fn from(isometry: Isometry3<T>) -> Self {
+        Self::new(isometry.translation, isometry.rotation, T::one())
+    }
+}
+impl<T> From<Isometry3<T>> for Affine3<T>
+    where T: Float
+{
+    fn from(isometry: Isometry3<T>) -> Self {
+        Self::from_parts(isometry.rotation.to_matrix3(), isometry.translation)
+    }
+}
+impl<T> From<Similarity3<T>> for Affine3<T>
+    where T: Float
+{
+    fn from(similarity: Similarity3<T>) -> Self {
+        Self::from_parts(similarity.rotation.to_matrix3() * similarity.scale, similarity.translation)
+    }
+}
+
+impl<T> From<Isometry3<T>> for Matrix4<T>
+    where T: Float
+{
+    fn from(isometry: Isometry3<T>) -> Self {
+        isometry.to_matrix4()
+    }
+}
+impl<T> From<Similarity3<T>> for Matrix4<T>
+    where T: Float
+{
+    fn from(similarity: Similarity3<T>) -> Self {
+        similarity.to_matrix4()
+    }
+}
+impl<T> From<Affine3<T>> for Matrix4<T>
+    where T: Float
+{
+    fn from(affine: Affine3<T>) -> Self {
+        affine.to_matrix4()
+    }
+}
+impl<T> From<Transform2D<T>> for Matrix3<T>
+    where T: Float
+{
+    fn from(transform: Transform2D<T>) -> Self {
+        transform.matrix
+    }
+}
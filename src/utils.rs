@@ -1,18 +1,8 @@
 pub trait Pad {
-    fn pad(&self, des_len: usize) -> Self;
     fn pad_c(&self, des_len: usize) -> Self;
 }
 
 impl Pad for String {
-    fn pad(&self, des_len: usize) -> Self {
-        let diff = des_len - self.len();
-
-        let mut pad = String::new();
-        for _ in 0..diff {
-            pad.push(' ');
-        }
-        self.clone()
-    }
     fn pad_c(&self, des_len: usize) -> Self {
         let diff = des_len - self.len();
 
@@ -25,4 +15,27 @@ impl Pad for String {
         }
         format!("{}{}{}", pre, self, post)
     }
-}
\ No newline at end of file
+}
+
+/// Implements multiplication with the scalar on the left for the primitive types
+///
+/// Takes the type and the names of its const generic parameters, like `MatrixMN<R, C>`
+macro_rules! impl_left_scalar_mul {
+    ($type:ident $(<$($param:ident),+>)?) => {
+        impl_left_scalar_mul!(@scalars $type [$($($param),+)?], f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    };
+    (@scalars $type:ident $params:tt, $($scalar:ty),+) => {
+        $(
+            impl_left_scalar_mul!(@impl $type $params, $scalar);
+        )+
+    };
+    (@impl $type:ident [$($param:ident),*], $scalar:ty) => {
+        impl<$(const $param: usize),*> std::ops::Mul<$type<$scalar $(, $param)*>> for $scalar {
+            type Output = $type<$scalar $(, $param)*>;
+
+            fn mul(self, value: $type<$scalar $(, $param)*>) -> Self::Output {
+                value * self
+            }
+        }
+    };
+}
@@ -0,0 +1,174 @@
+use super::Vector;
+use super::{Vector1, Vector2, Vector3, Vector4, VectorN};
+use crate::error::ShapeError;
+
+use std::convert::TryFrom;
+use std::ops::{Add, Sub, Mul, Index, IndexMut};
+use num_traits::Num;
+
+use super::DVector;
+
+impl<T> DVector<T>
+    where T: Num + Copy
+{
+    pub fn new(values: Vec<T>) -> Self {
+        Self { values }
+    }
+    /// Returns a vector of the given length filled with zeros
+    pub fn zeros(len: usize) -> Self {
+        Self { values: vec![T::zero(); len] }
+    }
+    /// Returns a vector of the given length with all elements set to the given value
+    pub fn splat(len: usize, value: T) -> Self {
+        Self { values: vec![value; len] }
+    }
+    /// Returns the unit vector of the given length along the given axis
+    ///
+    /// Panics if the axis is out of bounds
+    pub fn unit(len: usize, axis: usize) -> Self {
+        let mut values = vec![T::zero(); len];
+        values[axis] = T::one();
+        Self { values }
+    }
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+    /// Returns the dot product of the two vectors
+    pub fn dot(&self, other: &Self) -> Result<T, ShapeError> {
+        self.check_len(other)?;
+        Ok(self.values.iter().zip(other.values.iter()).fold(T::zero(), |sum, (a, b)| sum + *a * *b))
+    }
+    fn check_len(&self, other: &Self) -> Result<(), ShapeError> {
+        if self.values.len() == other.values.len() {
+            Ok(())
+        }
+        else {
+            Err(ShapeError::new([self.values.len(), 1], [other.values.len(), 1]))
+        }
+    }
+    fn zip_with(&self, other: &Self, f: impl Fn(T, T) -> T) -> Result<Self, ShapeError> {
+        self.check_len(other)?;
+        let values = self.values.iter().zip(other.values.iter()).map(|(a, b)| f(*a, *b)).collect();
+        Ok(Self { values })
+    }
+}
+
+impl<T> Vector<T> for DVector<T>
+    where T: Num + Copy
+{
+    fn sum(&self) -> T {
+        self.values.iter().fold(T::zero(), |sum, value| sum + *value)
+    }
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<T> IntoIterator for DVector<T>
+    where T: Num + Copy
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<T, const N: usize> From<VectorN<T, N>> for DVector<T>
+    where T: Num + Copy
+{
+    fn from(vec: VectorN<T, N>) -> Self {
+        Self { values: vec.values.to_vec() }
+    }
+}
+impl<T, const N: usize> TryFrom<DVector<T>> for VectorN<T, N>
+    where T: Num + Copy
+{
+    type Error = ShapeError;
+
+    fn try_from(vec: DVector<T>) -> Result<Self, ShapeError> {
+        if vec.values.len() != N {
+            return Err(ShapeError::new([N, 1], [vec.values.len(), 1]));
+        }
+        let mut values = [T::zero(); N];
+        values.copy_from_slice(&vec.values);
+        Ok(VectorN::new(values))
+    }
+}
+
+/// Implements the conversions between a `DVector` and one of `Vector1` to `Vector4`
+macro_rules! impl_dvector_conversions {
+    ($vector:ident, $len:expr) => {
+        impl<T> From<$vector<T>> for DVector<T>
+            where T: Num + Copy
+        {
+            fn from(vec: $vector<T>) -> Self {
+                DVector::from(VectorN::from(vec))
+            }
+        }
+        impl<T> TryFrom<DVector<T>> for $vector<T>
+            where T: Num + Copy
+        {
+            type Error = ShapeError;
+
+            fn try_from(vec: DVector<T>) -> Result<Self, ShapeError> {
+                VectorN::<T, $len>::try_from(vec).map($vector::from)
+            }
+        }
+    };
+}
+
+impl_dvector_conversions!(Vector1, 1);
+impl_dvector_conversions!(Vector2, 2);
+impl_dvector_conversions!(Vector3, 3);
+impl_dvector_conversions!(Vector4, 4);
+
+impl<T> Add for &DVector<T>
+    where T: Num + Copy
+{
+    type Output = Result<DVector<T>, ShapeError>;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+impl<T> Sub for &DVector<T>
+    where T: Num + Copy
+{
+    type Output = Result<DVector<T>, ShapeError>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+impl<T> Mul for &DVector<T>
+    where T: Num + Copy
+{
+    type Output = Result<DVector<T>, ShapeError>;
+
+    /// Multiplies the vectors element by element
+    fn mul(self, other: Self) -> Self::Output {
+        self.zip_with(other, |a, b| a * b)
+    }
+}
+
+impl<T> Index<usize> for DVector<T>
+    where T: Num + Copy
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+impl<T> IndexMut<usize> for DVector<T>
+    where T: Num + Copy
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.values[index]
+    }
+}
@@ -0,0 +1,181 @@
+use super::{Vector1, Vector2, Vector3, Vector4, VectorN};
+use crate::angles::Rad;
+
+use num_traits::{Float, Num};
+
+/// Implements the methods that only need the elements to be numbers for one of `Vector1` to `Vector4`
+macro_rules! impl_num_geometry {
+    ($vector:ident { $($field:ident),+ }) => {
+        impl<T> $vector<T>
+            where T: Num + Copy
+        {
+            /// Returns the dot product of the two vectors
+            pub fn dot(&self, other: Self) -> T {
+                T::zero() $(+ self.$field * other.$field)+
+            }
+            num_geometry_methods!();
+        }
+    };
+}
+
+/// Methods shared by all vector types that only need `dot`, `Sub`, `Add` and `Mul<T>`
+macro_rules! num_geometry_methods {
+    () => {
+        /// Returns the squared length of the vector, cheaper than `magnitude`
+        pub fn magnitude_squared(&self) -> T {
+            self.dot(*self)
+        }
+        /// Returns the squared distance between the two vectors, cheaper than `distance`
+        pub fn distance_squared(&self, other: Self) -> T {
+            let difference = other - *self;
+            difference.dot(difference)
+        }
+        /// Returns the linear interpolation between the two vectors
+        ///
+        /// t: 0 returns self and 1 returns other
+        pub fn lerp(&self, other: Self, t: T) -> Self {
+            *self + (other - *self) * t
+        }
+    };
+}
+
+/// Methods shared by all vector types that need the elements to be floats
+macro_rules! float_geometry_methods {
+    () => {
+        /// Returns the length of the vector
+        pub fn magnitude(&self) -> T {
+            self.magnitude_squared().sqrt()
+        }
+        /// Scales the vector to unit length in place
+        ///
+        /// A zero vector results in NaN values, see `try_normalize` and `normalize_or_zero`
+        pub fn normalize(&mut self) {
+            *self = self.normalized();
+        }
+        /// Returns the vector scaled to unit length
+        ///
+        /// A zero vector results in NaN values, use `try_normalize` or `normalize_or_zero` if that can happen
+        pub fn normalized(&self) -> Self {
+            *self / self.magnitude_squared().sqrt()
+        }
+        /// Returns the vector scaled to unit length, or `None` if its length is at most `eps`
+        pub fn try_normalize(&self, eps: T) -> Option<Self> {
+            let magnitude = self.magnitude_squared().sqrt();
+            if magnitude > eps {
+                Some(*self / magnitude)
+            }
+            else {
+                None
+            }
+        }
+        /// Returns the vector scaled to unit length, or a zero vector if its length is zero
+        pub fn normalize_or_zero(&self) -> Self {
+            self.try_normalize(T::zero()).unwrap_or_else(Self::zero)
+        }
+        /// Returns the vector in the same direction with the given length
+        ///
+        /// A zero vector stays zero
+        pub fn with_length(&self, length: T) -> Self {
+            self.normalize_or_zero() * length
+        }
+        /// Returns the vector with its length clamped between `min` and `max`
+        ///
+        /// A zero vector stays zero
+        pub fn clamp_length(&self, min: T, max: T) -> Self {
+            let magnitude = self.magnitude_squared().sqrt();
+            if magnitude < min {
+                self.with_length(min)
+            }
+            else if magnitude > max {
+                self.with_length(max)
+            }
+            else {
+                *self
+            }
+        }
+        /// Returns the distance between the two vectors
+        pub fn distance(&self, other: Self) -> T {
+            self.distance_squared(other).sqrt()
+        }
+        /// Returns the angle between the two vectors, in the range [0, π]
+        ///
+        /// Returns `None` if the angle is undefined, when either vector is zero or has NaN elements
+        pub fn angle_between(&self, other: Self) -> Option<Rad<T>> {
+            let cos = self.dot(other) / (self.dot(*self) * other.dot(other)).sqrt();
+            if cos.is_nan() {
+                return None;
+            }
+            Some(Rad::acos(cos.max(-T::one()).min(T::one())))
+        }
+        /// Returns the part of self that is parallel to other
+        pub fn project_onto(&self, other: Self) -> Self {
+            other * (self.dot(other) / other.dot(other))
+        }
+        /// Returns the part of self that is perpendicular to other
+        pub fn reject_from(&self, other: Self) -> Self {
+            *self - self.project_onto(other)
+        }
+        /// Returns self reflected off a surface with the given normal
+        ///
+        /// normal: unit length normal of the surface
+        pub fn reflect(&self, normal: Self) -> Self {
+            *self - normal * ((T::one() + T::one()) * self.dot(normal))
+        }
+        /// Returns self refracted through a surface with the given normal
+        ///
+        /// self: unit length direction towards the surface
+        /// normal: unit length normal of the surface, pointing against self
+        /// eta: ratio between the refractive indices on the incoming and the outgoing side
+        ///
+        /// Returns `None` on total internal reflection
+        pub fn refract(&self, normal: Self, eta: T) -> Option<Self> {
+            let cos = self.dot(normal);
+            let k = T::one() - eta * eta * (T::one() - cos * cos);
+            if k < T::zero() {
+                return None;
+            }
+            Some(*self * eta - normal * (eta * cos + k.sqrt()))
+        }
+    };
+}
+
+impl_num_geometry!(Vector1 { x });
+impl_num_geometry!(Vector2 { x, y });
+impl_num_geometry!(Vector3 { x, y, z });
+impl_num_geometry!(Vector4 { x, y, z, w });
+
+impl<T, const N: usize> VectorN<T, N>
+    where T: Num + Copy
+{
+    /// Returns the dot product of the two vectors
+    pub fn dot(&self, other: Self) -> T {
+        self.values.iter().zip(other.values.iter()).fold(T::zero(), |sum, (a, b)| sum + *a * *b)
+    }
+    num_geometry_methods!();
+}
+
+impl<T> Vector1<T>
+    where T: Float
+{
+    float_geometry_methods!();
+}
+impl<T> Vector2<T>
+    where T: Float
+{
+    float_geometry_methods!();
+}
+impl<T> Vector3<T>
+    where T: Float
+{
+    float_geometry_methods!();
+}
+impl<T> Vector4<T>
+    where T: Float
+{
+    float_geometry_methods!();
+}
+impl<T, const N: usize> VectorN<T, N>
+    where T: Float
+{
+    float_geometry_methods!();
+}
@@ -0,0 +1,189 @@
+use super::{Vector, Vector1, Vector2, Vector3, Vector4, VectorN};
+use super::{FromVector1, FromVector2, FromVector3, FromVector4};
+use super::{ToVector1, ToVector2, ToVector3, ToVector4};
+
+use std::ops::{Add, Sub, Mul, Index, IndexMut};
+use num_traits::{Num, Zero};
+
+/// Implements one of `Vector1` to `Vector4` by converting to and from the `VectorN` with the same number of elements
+///
+/// The arithmetic is only written once, in `VectorN`, the named vectors just add the named elements on top
+macro_rules! impl_named_vector {
+    ($vector:ident, $n:literal { $($field:ident: $unit:ident),+ }) => {
+        impl<T> $vector<T>
+            where T: Num + Copy
+        {
+            pub fn new($($field: T),+) -> Self {
+                Self { $($field),+ }
+            }
+            /// Returns a vector with all elements set to zero
+            pub fn zero() -> Self {
+                VectorN::zero().into()
+            }
+            /// Returns a vector with all elements set to the given value
+            pub fn splat(value: T) -> Self {
+                VectorN::splat(value).into()
+            }
+            $(
+                #[doc = concat!("Returns the unit vector along the ", stringify!($field), "-axis")]
+                pub fn $unit() -> Self {
+                    let mut vec = Self::zero();
+                    vec.$field = T::one();
+                    vec
+                }
+            )+
+        }
+
+        impl<T> Vector<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn sum(&self) -> T {
+                VectorN::from(*self).sum()
+            }
+            fn len(&self) -> usize {
+                $n
+            }
+        }
+
+        impl<T> Zero for $vector<T>
+            where T: Num + Copy
+        {
+            fn zero() -> Self {
+                Self::zero()
+            }
+            fn is_zero(&self) -> bool {
+                VectorN::from(*self).is_zero()
+            }
+        }
+
+        impl<T> From<$vector<T>> for VectorN<T, $n>
+            where T: Num + Copy
+        {
+            fn from(vec: $vector<T>) -> Self {
+                Self::new([$(vec.$field),+])
+            }
+        }
+        impl<T> From<VectorN<T, $n>> for $vector<T>
+            where T: Num + Copy
+        {
+            fn from(vec: VectorN<T, $n>) -> Self {
+                let [$($field),+] = vec.values;
+                Self { $($field),+ }
+            }
+        }
+
+        impl<T> FromVector1<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn from_vec_1(vec: Vector1<T>) -> Self {
+                VectorN::from(vec).resize().into()
+            }
+        }
+        impl<T> FromVector2<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn from_vec_2(vec: Vector2<T>) -> Self {
+                VectorN::from(vec).resize().into()
+            }
+        }
+        impl<T> FromVector3<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn from_vec_3(vec: Vector3<T>) -> Self {
+                VectorN::from(vec).resize().into()
+            }
+        }
+        impl<T> FromVector4<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn from_vec_4(vec: Vector4<T>) -> Self {
+                VectorN::from(vec).resize().into()
+            }
+        }
+
+        impl<T> ToVector1<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn to_vec_1(self) -> Vector1<T> {
+                VectorN::from(self).resize().into()
+            }
+        }
+        impl<T> ToVector2<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn to_vec_2(self) -> Vector2<T> {
+                VectorN::from(self).resize().into()
+            }
+        }
+        impl<T> ToVector3<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn to_vec_3(self) -> Vector3<T> {
+                VectorN::from(self).resize().into()
+            }
+        }
+        impl<T> ToVector4<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn to_vec_4(self) -> Vector4<T> {
+                VectorN::from(self).resize().into()
+            }
+        }
+
+        impl_named_vector!(@ops $vector, Vector1, Vector2, Vector3, Vector4);
+
+        impl<T> Index<usize> for $vector<T>
+            where T: Num + Copy
+        {
+            type Output = T;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                [$(&self.$field),+][index]
+            }
+        }
+        impl<T> IndexMut<usize> for $vector<T>
+            where T: Num + Copy
+        {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                IntoIterator::into_iter([$(&mut self.$field),+]).nth(index).expect("Index out of bounds")
+            }
+        }
+    };
+    (@ops $vector:ident, $($other:ident),+) => {
+        $(
+            impl<T> Add<$other<T>> for $vector<T>
+                where T: Num + Copy
+            {
+                type Output = Self;
+
+                fn add(self, other: $other<T>) -> Self {
+                    VectorN::from(self).zip_with(VectorN::from(other), |a, b| a + b).into()
+                }
+            }
+            impl<T> Sub<$other<T>> for $vector<T>
+                where T: Num + Copy
+            {
+                type Output = Self;
+
+                fn sub(self, other: $other<T>) -> Self {
+                    VectorN::from(self).zip_with(VectorN::from(other), |a, b| a - b).into()
+                }
+            }
+            impl<T> Mul<$other<T>> for $vector<T>
+                where T: Num + Copy
+            {
+                type Output = Self;
+
+                /// Multiplies the vectors element by element
+                fn mul(self, other: $other<T>) -> Self {
+                    VectorN::from(self).zip_with(VectorN::from(other), |a, b| a * b).into()
+                }
+            }
+        )+
+    };
+}
+
+impl_named_vector!(Vector1, 1 { x: unit_x });
+impl_named_vector!(Vector2, 2 { x: unit_x, y: unit_y });
+impl_named_vector!(Vector3, 3 { x: unit_x, y: unit_y, z: unit_z });
+impl_named_vector!(Vector4, 4 { x: unit_x, y: unit_y, z: unit_z, w: unit_w });
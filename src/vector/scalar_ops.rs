@@ -0,0 +1,73 @@
+use super::{Vector1, Vector2, Vector3, Vector4};
+
+use std::ops::{Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+use num_traits::Num;
+
+/// Implements the scalar operators, negation and the assigning operators for one of `Vector1` to `Vector4`
+macro_rules! impl_scalar_ops {
+    ($vector:ident { $($field:ident),+ }) => {
+        impl<T> Mul<T> for $vector<T>
+            where T: Num + Copy
+        {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self {
+                Self { $($field: self.$field * scalar),+ }
+            }
+        }
+        impl<T> Div<T> for $vector<T>
+            where T: Num + Copy
+        {
+            type Output = Self;
+
+            fn div(self, scalar: T) -> Self {
+                Self { $($field: self.$field / scalar),+ }
+            }
+        }
+        impl<T> Neg for $vector<T>
+            where T: Num + Copy + Neg<Output = T>
+        {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T> AddAssign for $vector<T>
+            where T: Num + Copy
+        {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+        impl<T> SubAssign for $vector<T>
+            where T: Num + Copy
+        {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+        impl<T> MulAssign<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn mul_assign(&mut self, scalar: T) {
+                *self = *self * scalar;
+            }
+        }
+        impl<T> DivAssign<T> for $vector<T>
+            where T: Num + Copy
+        {
+            fn div_assign(&mut self, scalar: T) {
+                *self = *self / scalar;
+            }
+        }
+
+        impl_left_scalar_mul!($vector);
+    };
+}
+
+impl_scalar_ops!(Vector1 { x });
+impl_scalar_ops!(Vector2 { x, y });
+impl_scalar_ops!(Vector3 { x, y, z });
+impl_scalar_ops!(Vector4 { x, y, z, w });
@@ -0,0 +1,63 @@
+use super::{Vector2, Vector3, Vector4, VectorN};
+
+use num_traits::Num;
+use paste::paste;
+
+/// Implements swizzles, methods that build a new vector from the elements in the order of their name
+///
+/// `swizzle!(Vector3 { x, y, z })` gives every getter with two to four elements, like `Vector3::zx`
+/// returning `Vector2::new(self.z, self.x)`, and every setter with two or more distinct elements,
+/// like `Vector3::set_zx`, which writes the elements of a vector to the named fields in order.
+macro_rules! swizzle {
+    ($vector:ident { $($field:ident),+ }) => {
+        impl<T> $vector<T>
+            where T: Num + Copy
+        {
+            swizzle!(@get Vector2 [$($field)+] [] [_ _]);
+            swizzle!(@get Vector3 [$($field)+] [] [_ _ _]);
+            swizzle!(@get Vector4 [$($field)+] [] [_ _ _ _]);
+            swizzle!(@set Vector2 [$($field)+] [] [_ _]);
+            swizzle!(@set Vector3 [$($field)+] [] [_ _ _]);
+            swizzle!(@set Vector4 [$($field)+] [] [_ _ _ _]);
+        }
+    };
+
+    // Getters, every field can be picked at every position
+    (@get $target:ident $fields:tt [$($picked:ident)+] []) => {
+        paste! {
+            pub fn [<$($picked)+>](&self) -> $target<T> {
+                $target::new($(self.$picked),+)
+            }
+        }
+    };
+    (@get $target:ident $fields:tt $picked:tt [_ $($left:tt)*]) => {
+        swizzle!(@get_each $target $fields $picked [$($left)*] $fields);
+    };
+    (@get_each $target:ident $fields:tt [$($picked:ident)*] $left:tt [$next:ident $($rest:ident)*]) => {
+        swizzle!(@get $target $fields [$($picked)* $next] $left);
+        swizzle!(@get_each $target $fields [$($picked)*] $left [$($rest)*]);
+    };
+    (@get_each $target:ident $fields:tt $picked:tt $left:tt []) => {};
+
+    // Setters, every field can only be picked once, so vectors get no setters wider than themselves
+    (@set $target:ident $unused:tt [$($picked:ident)+] []) => {
+        paste! {
+            pub fn [<set_ $($picked)+>](&mut self, vec: $target<T>) {
+                let mut values = VectorN::from(vec).into_iter();
+                $(self.$picked = values.next().unwrap();)+
+            }
+        }
+    };
+    (@set $target:ident $unused:tt $picked:tt [_ $($left:tt)*]) => {
+        swizzle!(@set_each $target [] $unused $picked [$($left)*]);
+    };
+    (@set_each $target:ident [$($before:ident)*] [$next:ident $($after:ident)*] [$($picked:ident)*] $left:tt) => {
+        swizzle!(@set $target [$($before)* $($after)*] [$($picked)* $next] $left);
+        swizzle!(@set_each $target [$($before)* $next] [$($after)*] [$($picked)*] $left);
+    };
+    (@set_each $target:ident $before:tt [] $picked:tt $left:tt) => {};
+}
+
+swizzle!(Vector2 { x, y });
+swizzle!(Vector3 { x, y, z });
+swizzle!(Vector4 { x, y, z, w });
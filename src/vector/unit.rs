@@ -0,0 +1,75 @@
+use super::{Normalize, Vector1, Vector2, Vector3, Vector4, VectorN};
+
+use std::ops::{Deref, Neg};
+use num_traits::Float;
+
+use super::Unit;
+
+impl<V> Unit<V> {
+    /// Wraps a vector without checking its length
+    ///
+    /// The vector is expected to already have unit length
+    pub const fn new_unchecked(vector: V) -> Self {
+        Self { vector }
+    }
+    /// Returns the wrapped vector
+    pub fn into_inner(self) -> V {
+        self.vector
+    }
+}
+impl<V> Unit<V>
+    where V: Normalize
+{
+    /// Normalizes the vector and wraps it
+    ///
+    /// A zero vector results in NaN values, use `try_new` if that can happen
+    pub fn new_normalize(vector: V) -> Self {
+        Self { vector: vector.normalized() }
+    }
+    /// Normalizes the vector and wraps it, or returns `None` if its length is at most `eps`
+    pub fn try_new(vector: V, eps: V::Scalar) -> Option<Self> {
+        vector.try_normalize(eps).map(|vector| Self { vector })
+    }
+}
+
+/// Implements `Normalize` for a vector type by calling its inherent methods
+macro_rules! impl_normalize {
+    ($vector:ty, [$($generics:tt)*]) => {
+        impl<T, $($generics)*> Normalize for $vector
+            where T: Float
+        {
+            type Scalar = T;
+
+            fn normalized(&self) -> Self {
+                <$vector>::normalized(self)
+            }
+            fn try_normalize(&self, eps: T) -> Option<Self> {
+                <$vector>::try_normalize(self, eps)
+            }
+        }
+    };
+}
+
+impl_normalize!(Vector1<T>, []);
+impl_normalize!(Vector2<T>, []);
+impl_normalize!(Vector3<T>, []);
+impl_normalize!(Vector4<T>, []);
+impl_normalize!(VectorN<T, N>, [const N: usize]);
+
+impl<V> Deref for Unit<V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.vector
+    }
+}
+
+impl<V> Neg for Unit<V>
+    where V: Neg<Output = V>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { vector: -self.vector }
+    }
+}
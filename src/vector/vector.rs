@@ -2,15 +2,43 @@ mod vector1;
 mod vector2;
 mod vector3;
 mod vector4;
+mod vector_n;
+mod named;
+mod dvector;
+mod scalar_ops;
+mod geometry;
+mod unit;
+mod swizzle;
 pub mod vector_iterator;
 
-use num_traits::Num;
+use num_traits::{Float, Num};
 
 pub trait Vector<T>
     where T: Num + Copy
 {
     fn sum(&self) -> T;
     fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A vector with `N` elements.
+///
+/// `Vector1` to `Vector4` convert to and from the vector with the same number of elements.
+pub struct VectorN<T, const N: usize>
+    where T: Num + Copy
+{
+    pub values: [T; N]
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A heap allocated vector with a length only known at runtime.
+pub struct DVector<T>
+    where T: Num + Copy
+{
+    pub values: Vec<T>
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -47,42 +75,82 @@ pub struct Vector4<T>
     pub w: T,
 }
 
+/// Vectors that can be scaled to unit length, used by `Unit`
+pub trait Normalize: Copy {
+    type Scalar: Float;
+
+    fn normalized(&self) -> Self;
+    fn try_normalize(&self, eps: Self::Scalar) -> Option<Self>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A vector that is known to have unit length.
+///
+/// Dereferences to the wrapped vector, use `into_inner` to take it out.
+pub struct Unit<V> {
+    vector: V,
+}
+
+/// Creates a vector from a 1D vector
+///
+/// Elements the 1D vector lacks are set to zero, elements it has beyond the new vector are lost
 pub trait FromVector1<T>
     where T: Num + Copy
 {
     fn from_vec_1(vec: Vector1<T>) -> Self;
 }
+/// Creates a vector from a 2D vector
+///
+/// Elements the 2D vector lacks are set to zero, elements it has beyond the new vector are lost
 pub trait FromVector2<T>
     where T: Num + Copy
 {
     fn from_vec_2(vec: Vector2<T>) -> Self;
 }
+/// Creates a vector from a 3D vector
+///
+/// Elements the 3D vector lacks are set to zero, elements it has beyond the new vector are lost
 pub trait FromVector3<T>
     where T: Num + Copy
 {
     fn from_vec_3(vec: Vector3<T>) -> Self;
 }
+/// Creates a vector from a 4D vector
+///
+/// Elements the 4D vector lacks are set to zero, elements it has beyond the new vector are lost
 pub trait FromVector4<T>
     where T: Num + Copy
 {
     fn from_vec_4(vec: Vector4<T>) -> Self;
 }
 
+/// Converts a vector to a 1D vector
+///
+/// Elements the vector lacks are set to zero, elements beyond the 1D vector are lost
 pub trait ToVector1<T>
     where T: Num + Copy
 {
     fn to_vec_1(self) -> Vector1<T>;
 }
+/// Converts a vector to a 2D vector
+///
+/// Elements the vector lacks are set to zero, elements beyond the 2D vector are lost
 pub trait ToVector2<T>
     where T: Num + Copy
 {
     fn to_vec_2(self) -> Vector2<T>;
 }
+/// Converts a vector to a 3D vector
+///
+/// Elements the vector lacks are set to zero, elements beyond the 3D vector are lost
 pub trait ToVector3<T>
     where T: Num + Copy
 {
     fn to_vec_3(self) -> Vector3<T>;
 }
+/// Converts a vector to a 4D vector
+///
+/// Elements the vector lacks are set to zero, elements beyond the 4D vector are lost
 pub trait ToVector4<T>
     where T: Num + Copy
 {
@@ -0,0 +1,192 @@
+use super::Vector;
+
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign, Index, IndexMut};
+use std::iter::IntoIterator;
+use num_traits::{Num, Zero};
+
+use super::VectorN;
+
+impl<T, const N: usize> VectorN<T, N>
+    where T: Num + Copy
+{
+    pub fn new(values: [T; N]) -> Self {
+        Self { values }
+    }
+    /// Returns a vector with all elements set to zero
+    pub fn zero() -> Self {
+        Self { values: [T::zero(); N] }
+    }
+    /// Returns a vector with all elements set to the given value
+    pub fn splat(value: T) -> Self {
+        Self { values: [value; N] }
+    }
+    /// Returns the unit vector along the given axis
+    ///
+    /// Panics if the axis is out of bounds
+    pub fn unit(axis: usize) -> Self {
+        let mut values = [T::zero(); N];
+        values[axis] = T::one();
+        Self { values }
+    }
+    /// Returns the vector with `f` applied to each element and the element of other at the same index
+    ///
+    /// Elements past the end of other are kept as they are
+    pub(crate) fn zip_with<F, const M: usize>(self, other: VectorN<T, M>, f: F) -> Self
+        where F: Fn(T, T) -> T
+    {
+        let mut values = self.values;
+        for (value, other_value) in values.iter_mut().zip(other.values.iter()) {
+            *value = f(*value, *other_value);
+        }
+        Self { values }
+    }
+    /// Returns the vector with `M` elements, dropping the last elements or padding with zero
+    pub(crate) fn resize<const M: usize>(self) -> VectorN<T, M> {
+        VectorN::zero().zip_with(self, |_, value| value)
+    }
+}
+
+impl<T, const N: usize> Vector<T> for VectorN<T, N>
+    where T: Num + Copy
+{
+    fn sum(&self) -> T {
+        self.values.iter().fold(T::zero(), |sum, value| sum + *value)
+    }
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+impl<T, const N: usize> Zero for VectorN<T, N>
+    where T: Num + Copy
+{
+    fn zero() -> Self {
+        Self::zero()
+    }
+    fn is_zero(&self) -> bool {
+        self.values.iter().all(|value| value.is_zero())
+    }
+}
+
+impl<T, const N: usize> IntoIterator for VectorN<T, N>
+    where T: Num + Copy
+{
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.values)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VectorN<T, N>
+    where T: Num + Copy
+{
+    fn from(values: [T; N]) -> Self {
+        Self { values }
+    }
+}
+
+impl<T, const N: usize> Add for VectorN<T, N>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+impl<T, const N: usize> Sub for VectorN<T, N>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+impl<T, const N: usize> Mul for VectorN<T, N>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    /// Multiplies the vectors element by element
+    fn mul(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a * b)
+    }
+}
+impl<T, const N: usize> Mul<T> for VectorN<T, N>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        Self { values: self.values.map(|value| value * scalar) }
+    }
+}
+impl<T, const N: usize> Div<T> for VectorN<T, N>
+    where T: Num + Copy
+{
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+        Self { values: self.values.map(|value| value / scalar) }
+    }
+}
+impl<T, const N: usize> Neg for VectorN<T, N>
+    where T: Num + Copy + Neg<Output = T>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { values: self.values.map(|value| -value) }
+    }
+}
+
+impl<T, const N: usize> AddAssign for VectorN<T, N>
+    where T: Num + Copy
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+impl<T, const N: usize> SubAssign for VectorN<T, N>
+    where T: Num + Copy
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+impl<T, const N: usize> MulAssign<T> for VectorN<T, N>
+    where T: Num + Copy
+{
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
+    }
+}
+impl<T, const N: usize> DivAssign<T> for VectorN<T, N>
+    where T: Num + Copy
+{
+    fn div_assign(&mut self, scalar: T) {
+        *self = *self / scalar;
+    }
+}
+
+impl_left_scalar_mul!(VectorN<N>);
+
+impl<T, const N: usize> Index<usize> for VectorN<T, N>
+    where T: Num + Copy
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+impl<T, const N: usize> IndexMut<usize> for VectorN<T, N>
+    where T: Num + Copy
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.values[index]
+    }
+}
@@ -0,0 +1,68 @@
+use linear_algebra::angles::{Deg, Rad};
+
+use std::f64::consts::{PI, TAU};
+
+#[test]
+fn conversions() {
+    assert_eq!(Rad::from(Deg(180.0)), Rad(PI));
+    assert_eq!(Deg::from(Rad(PI / 2.0)), Deg(90.0));
+
+    for &degrees in [-720.0f64, -45.5, 0.0, 12.25, 359.0, 1000.0].iter() {
+        let round_trip = Deg::from(Rad::from(Deg(degrees)));
+        assert!((round_trip.0 - degrees).abs() < 1e-12, "{} != {}", round_trip.0, degrees);
+    }
+    assert!((Deg(90.0f64).sin() - 1.0).abs() < 1e-15);
+    assert!((Rad::from(Deg::atan2(1.0, 1.0)).0 - PI / 4.0).abs() < 1e-15);
+}
+
+#[test]
+fn normalize_negative() {
+    assert_eq!(Deg(-90.0).normalize(), Deg(270.0));
+    assert_eq!(Deg(-450.0).normalize(), Deg(270.0));
+    assert!((Rad(-PI / 2.0).normalize().0 - 3.0 * PI / 2.0).abs() < 1e-15);
+
+    // A tiny negative angle must not round up to a full turn
+    let tiny = Rad(-1e-20).normalize();
+    assert!(tiny.0 >= 0.0 && tiny.0 < TAU);
+    assert!(Deg(-1e-20).normalize().0 < 360.0);
+}
+
+#[test]
+fn normalize_full_turns() {
+    for &turns in [-3.0, -1.0, 0.0, 1.0, 2.0].iter() {
+        assert_eq!(Deg(360.0 * turns).normalize(), Deg(0.0));
+        assert_eq!(Deg(360.0 * turns).normalize_signed(), Deg(0.0));
+
+        let radians = Rad(TAU * turns).normalize().0;
+        assert!(radians < 1e-12 || TAU - radians < 1e-12, "{}", radians);
+    }
+    assert_eq!(Deg(360.0).normalize(), Deg(0.0));
+    assert_eq!(Rad(TAU).normalize(), Rad(0.0));
+}
+
+#[test]
+fn normalize_signed_half_turn_boundary() {
+    assert_eq!(Deg(180.0).normalize_signed(), Deg(180.0));
+    assert_eq!(Deg(-180.0).normalize_signed(), Deg(180.0));
+    assert_eq!(Deg(540.0).normalize_signed(), Deg(180.0));
+    assert_eq!(Deg(181.0).normalize_signed(), Deg(-179.0));
+    assert_eq!(Deg(-181.0).normalize_signed(), Deg(179.0));
+
+    assert_eq!(Rad(PI).normalize_signed(), Rad(PI));
+    assert_eq!(Rad(-PI).normalize_signed(), Rad(PI));
+    assert!((Rad(PI + 0.5).normalize_signed().0 - (0.5 - PI)).abs() < 1e-15);
+}
+
+#[test]
+fn arithmetic() {
+    let mut angle = Deg(30.0) + Deg(60.0) - Deg(45.0);
+    assert_eq!(angle, Deg(45.0));
+    assert_eq!(-angle * 2.0, Deg(-90.0));
+    assert_eq!(angle / 3.0, Deg(15.0));
+    assert_eq!(Deg(90.0) / Deg(45.0), 2.0);
+
+    angle += Deg(10.0);
+    angle -= Deg(5.0);
+    assert_eq!(angle, Deg(50.0));
+    assert!(Deg(10.0) < Deg(20.0));
+}
@@ -0,0 +1,121 @@
+use linear_algebra::error::{ShapeError, SolveError};
+use linear_algebra::matrix::{DMatrix, Matrix3, Matrix4};
+use linear_algebra::vector::{DVector, Vector3, Vector4, VectorN};
+
+mod common;
+
+use common::assert_dmatrix_eq;
+
+fn covariance() -> Matrix3<f64> {
+    Matrix3::new([
+        [4.0, 12.0, -16.0],
+        [12.0, 37.0, -43.0],
+        [-16.0, -43.0, 98.0],
+    ])
+}
+
+#[test]
+fn cholesky_factor() {
+    let cholesky = covariance().cholesky().unwrap();
+    let expected: DMatrix<f64> = DMatrix::from_row_major(3, 3, vec![
+        2.0, 0.0, 0.0,
+        6.0, 1.0, 0.0,
+        -8.0, 5.0, 3.0,
+    ]).unwrap();
+
+    assert_dmatrix_eq(cholesky.l(), &expected, 1e-12);
+    assert!((cholesky.determinant() - covariance().determinant()).abs() < 1e-9);
+}
+
+#[test]
+fn cholesky_solve() {
+    let a: Matrix4<f64> = Matrix4::new([
+        [6.0, 3.0, 4.0, 8.0],
+        [3.0, 6.0, 5.0, 1.0],
+        [4.0, 5.0, 10.0, 7.0],
+        [8.0, 1.0, 7.0, 25.0],
+    ]);
+    let b = DVector::new(vec![1.0, 2.0, 3.0, 4.0]);
+    let x = a.cholesky().unwrap().solve(&b).unwrap();
+    let product = (&DMatrix::from(a) * &x).unwrap();
+
+    for i in 0..4 {
+        assert!((product[i] - b[i]).abs() < 1e-12);
+    }
+    assert_eq!(
+        a.cholesky().unwrap().solve(&DVector::zeros(3)),
+        Err(SolveError::Shape(ShapeError::new([4, 1], [3, 1])))
+    );
+}
+
+#[test]
+fn fixed_size_solve() {
+    let a = covariance();
+    let b = Vector3::new(1.0, -2.0, 0.5);
+    let cholesky = a.cholesky().unwrap();
+    let ldl = a.ldl().unwrap();
+
+    let x = cholesky.solve_vector(b).unwrap();
+    assert!((a * x - b).magnitude() < 1e-9);
+
+    let y: VectorN<f64, 3> = ldl.solve_vector(VectorN::from(b)).unwrap();
+    assert!((Vector3::from(y) - x).magnitude() < 1e-9);
+
+    assert!(matches!(cholesky.solve_vector(Vector4::<f64>::zero()), Err(SolveError::Shape(_))));
+    assert!(matches!(ldl.solve_vector(Vector4::<f64>::zero()), Err(SolveError::Shape(_))));
+}
+
+#[test]
+fn not_positive_definite() {
+    let indefinite: Matrix3<f64> = Matrix3::new([
+        [1.0, 2.0, 0.0],
+        [2.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+    ]);
+    assert!(indefinite.cholesky().is_none());
+    assert!(indefinite.ldl().is_none());
+
+    let semi_definite: Matrix3<f64> = Matrix3::new([
+        [1.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 0.0, 2.0],
+    ]);
+    assert!(semi_definite.cholesky().is_none());
+    assert!(DMatrix::<f64>::zeros(2, 3).cholesky().is_none());
+}
+
+#[test]
+fn ldl_factors() {
+    let a = DMatrix::from(covariance());
+    let ldl = a.ldl().unwrap();
+    let d = DMatrix::from_fn(3, 3, |i, j| if i == j { ldl.d()[i] } else { 0.0 });
+    let product = (&(ldl.l() * &d).unwrap() * &ldl.l().transpose()).unwrap();
+
+    assert_dmatrix_eq(&product, &a, 1e-12);
+    assert_eq!(ldl.d().values, vec![4.0, 1.0, 9.0]);
+
+    let b = DVector::new(vec![1.0, -2.0, 0.5]);
+    let x = ldl.solve(&b).unwrap();
+    let expected = a.cholesky().unwrap().solve(&b).unwrap();
+    for i in 0..3 {
+        assert!((x[i] - expected[i]).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn ldl_semi_definite() {
+    // Covariance of samples that all lie on the line y = x
+    let a: Matrix3<f64> = Matrix3::new([
+        [1.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 0.0, 2.0],
+    ]);
+    let ldl = a.ldl().unwrap();
+    let d = DMatrix::from_fn(3, 3, |i, j| if i == j { ldl.d()[i] } else { 0.0 });
+    let product = (&(ldl.l() * &d).unwrap() * &ldl.l().transpose()).unwrap();
+
+    assert_dmatrix_eq(&product, &DMatrix::from(a), 1e-12);
+    assert_eq!(ldl.d()[1], 0.0);
+    assert_eq!(ldl.determinant(), 0.0);
+    assert_eq!(ldl.solve(&DVector::new(vec![1.0, 1.0, 1.0])), Err(SolveError::Singular(1)));
+}
@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use linear_algebra::matrix::{DMatrix, MatrixMN};
+
+use num_traits::Float;
+use std::fmt::Debug;
+
+pub fn assert_matrix_eq<T, const R: usize, const C: usize>(a: MatrixMN<T, R, C>, b: MatrixMN<T, R, C>, epsilon: T)
+    where T: Float + Debug
+{
+    for i in 0..R {
+        for j in 0..C {
+            assert!((a[[i, j]] - b[[i, j]]).abs() <= epsilon, "{:?} != {:?}", a, b);
+        }
+    }
+}
+
+pub fn assert_dmatrix_eq(a: &DMatrix<f64>, b: &DMatrix<f64>, epsilon: f64) {
+    assert_eq!(a.shape(), b.shape());
+    for i in 0..a.rows() {
+        for j in 0..a.columns() {
+            assert!((a[[i, j]] - b[[i, j]]).abs() <= epsilon, "{:?} != {:?}", a, b);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use linear_algebra::error::ShapeError;
+use linear_algebra::matrix::{DMatrix, Layout, Matrix3, MatrixMN};
+use linear_algebra::vector::{DVector, Vector2, Vector3, VectorN};
+
+use std::convert::TryFrom;
+
+/// Returns the 2x3 matrix with rows `[1, 2, 3]` and `[4, 5, 6]` in the given layout
+fn wide(layout: Layout) -> DMatrix<i32> {
+    DMatrix::from_row_major(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap().to_layout(layout)
+}
+
+#[test]
+fn layouts_hold_the_same_matrix() {
+    let row_major = wide(Layout::RowMajor);
+    let column_major = DMatrix::from_column_major(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap();
+
+    assert_eq!(row_major, column_major);
+    assert_eq!(column_major.layout(), Layout::ColumnMajor);
+    assert_eq!(row_major.row_vector(1), column_major.row_vector(1));
+    assert_eq!(row_major.column_vector(2), DVector::new(vec![3, 6]));
+    assert_eq!(column_major.column_vector(2), DVector::new(vec![3, 6]));
+}
+
+#[test]
+fn to_layout_round_trip() {
+    let matrix = wide(Layout::RowMajor);
+    let column_major = matrix.to_layout(Layout::ColumnMajor);
+
+    assert_eq!(column_major.layout(), Layout::ColumnMajor);
+    assert_eq!(column_major.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    assert_eq!(column_major, matrix);
+
+    let back = column_major.to_layout(Layout::RowMajor);
+    assert_eq!(back.layout(), Layout::RowMajor);
+    assert_eq!(back.as_slice(), matrix.as_slice());
+}
+
+#[test]
+fn transpose_preserves_values() {
+    for layout in [Layout::RowMajor, Layout::ColumnMajor].iter() {
+        let matrix = wide(*layout);
+        let transposed = matrix.transpose();
+
+        assert_eq!(transposed.shape(), [3, 2]);
+        assert_eq!(transposed, DMatrix::from_row_major(3, 2, vec![1, 4, 2, 5, 3, 6]).unwrap());
+        assert_eq!(transposed.transpose(), matrix);
+        for i in 0..2 {
+            assert_eq!(transposed.column_vector(i), matrix.row_vector(i));
+        }
+    }
+}
+
+#[test]
+fn mixed_layout_arithmetic() {
+    let square = DMatrix::from_row_major(3, 2, vec![1, 0, 2, 1, 0, 3]).unwrap();
+    let expected_product = DMatrix::from_row_major(2, 2, vec![5, 11, 14, 23]).unwrap();
+    let expected_sum = DMatrix::from_row_major(2, 3, vec![2, 4, 6, 8, 10, 12]).unwrap();
+    let vector = DVector::new(vec![1, 0, -1]);
+
+    for a_layout in [Layout::RowMajor, Layout::ColumnMajor].iter() {
+        for b_layout in [Layout::RowMajor, Layout::ColumnMajor].iter() {
+            let a = wide(*a_layout);
+            let b = square.to_layout(*b_layout);
+
+            assert_eq!((&a * &b).unwrap(), expected_product);
+            assert_eq!((&a + &wide(*b_layout)).unwrap(), expected_sum);
+            assert_eq!((&a - &wide(*b_layout)).unwrap(), DMatrix::zeros(2, 3));
+        }
+        assert_eq!((&wide(*a_layout) * &vector).unwrap(), DVector::new(vec![-2, -2]));
+    }
+}
+
+#[test]
+fn shape_errors() {
+    let matrix = wide(Layout::RowMajor);
+
+    assert_eq!(DMatrix::from_row_major(2, 3, vec![1, 2, 3]).unwrap_err(), ShapeError::new([6, 1], [3, 1]));
+    assert_eq!(DMatrix::<i32>::from_column_major(1, 2, vec![]).unwrap_err(), ShapeError::new([2, 1], [0, 1]));
+    assert_eq!((&matrix * &matrix).unwrap_err(), ShapeError::new([3, 3], [2, 3]));
+    assert_eq!((&matrix * &DVector::new(vec![1, 2])).unwrap_err(), ShapeError::new([3, 1], [2, 1]));
+    assert_eq!((&matrix + &matrix.transpose()).unwrap_err(), ShapeError::new([2, 3], [3, 2]));
+    assert_eq!((&matrix - &DMatrix::zeros(2, 2)).unwrap_err(), ShapeError::new([2, 3], [2, 2]));
+
+    let a = DVector::new(vec![1, 2, 3]);
+    let b = DVector::new(vec![1, 2]);
+    assert_eq!(a.dot(&b).unwrap_err(), ShapeError::new([3, 1], [2, 1]));
+    assert_eq!((&a + &b).unwrap_err(), ShapeError::new([3, 1], [2, 1]));
+    assert_eq!((&a - &b).unwrap_err(), ShapeError::new([3, 1], [2, 1]));
+    assert_eq!((&a * &b).unwrap_err(), ShapeError::new([3, 1], [2, 1]));
+}
+
+#[test]
+fn conversions_to_fixed_size() {
+    for layout in [Layout::RowMajor, Layout::ColumnMajor].iter() {
+        let fixed = MatrixMN::<i32, 2, 3>::try_from(wide(*layout)).unwrap();
+        assert_eq!(fixed.values, [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(DMatrix::from(fixed), wide(*layout));
+
+        assert_eq!(
+            Matrix3::<i32>::try_from(wide(*layout)).unwrap_err(),
+            ShapeError::new([3, 3], [2, 3])
+        );
+    }
+
+    let vector = DVector::new(vec![1, 2, 3]);
+    assert_eq!(VectorN::<i32, 3>::try_from(vector.clone()).unwrap().values, [1, 2, 3]);
+    assert_eq!(Vector3::try_from(vector.clone()).unwrap(), Vector3::new(1, 2, 3));
+    assert_eq!(Vector2::try_from(vector.clone()).unwrap_err(), ShapeError::new([2, 1], [3, 1]));
+    assert_eq!(VectorN::<i32, 4>::try_from(vector).unwrap_err(), ShapeError::new([4, 1], [3, 1]));
+    assert_eq!(DVector::from(Vector2::new(7, 8)), DVector::new(vec![7, 8]));
+}
+
+#[test]
+fn layout_slices() {
+    let row_major = wide(Layout::RowMajor);
+    let column_major = wide(Layout::ColumnMajor);
+
+    assert_eq!(row_major.as_row_major_slice(), Some(&[1, 2, 3, 4, 5, 6][..]));
+    assert_eq!(row_major.as_column_major_slice(), None);
+    assert_eq!(column_major.as_column_major_slice(), Some(&[1, 4, 2, 5, 3, 6][..]));
+    assert_eq!(column_major.as_row_major_slice(), None);
+    assert_eq!(row_major.transpose().as_column_major_slice(), Some(&[1, 2, 3, 4, 5, 6][..]));
+}
+
+#[test]
+fn rows_and_columns() {
+    for layout in [Layout::RowMajor, Layout::ColumnMajor].iter() {
+        let mut matrix = wide(*layout);
+
+        assert_eq!(matrix[[1, 0]], 4);
+        assert_eq!(matrix.get(1, 0), Some(4));
+        assert_eq!(matrix.get(0, 3), None);
+        assert_eq!(matrix.get(2, 0), None);
+        assert_eq!(matrix.row(1), DVector::new(vec![4, 5, 6]));
+        assert_eq!(matrix.col(2), DVector::new(vec![3, 6]));
+
+        matrix.set_row(0, &DVector::new(vec![0, 0, 0])).unwrap();
+        matrix.set_col(2, &DVector::new(vec![1, 1])).unwrap();
+        assert_eq!(matrix, DMatrix::from_row_major(2, 3, vec![0, 0, 1, 4, 5, 1]).unwrap());
+        assert_eq!(matrix.layout(), *layout);
+
+        assert_eq!(matrix.set_row(0, &DVector::new(vec![1, 2])), Err(ShapeError::new([3, 1], [2, 1])));
+        assert_eq!(matrix.set_col(0, &DVector::new(vec![1, 2, 3])), Err(ShapeError::new([2, 1], [3, 1])));
+    }
+}
+
+#[test]
+fn views() {
+    let matrix = DMatrix::from_fn(3, 4, |i, j| (i * 10 + j) as i32);
+    let view = matrix.view(1, 2, 2, 2);
+
+    assert_eq!(view[[0, 1]], matrix[[1, 3]]);
+    assert_eq!(view.to_matrix(), DMatrix::from_row_major(2, 2, vec![12, 13, 22, 23]).unwrap());
+
+    let tall = matrix.view(0, 1, 3, 2);
+    assert_eq!(tall.shape(), [3, 2]);
+    assert_eq!(tall[[2, 1]], matrix[[2, 2]]);
+    assert_eq!(tall.to_matrix(), DMatrix::from_row_major(3, 2, vec![1, 2, 11, 12, 21, 22]).unwrap());
+
+    let wide = matrix.view(1, 0, 2, 3);
+    assert_eq!(wide.to_matrix(), DMatrix::from_row_major(2, 3, vec![10, 11, 12, 20, 21, 22]).unwrap());
+    assert_eq!(wide.view(1, 1, 1, 2).to_matrix(), DMatrix::from_row_major(1, 2, vec![21, 22]).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds")]
+fn view_index_out_of_bounds() {
+    let matrix = DMatrix::from_fn(3, 4, |i, j| (i * 10 + j) as i32);
+    let _ = matrix.view(0, 0, 2, 3)[[2, 0]];
+}
@@ -0,0 +1,125 @@
+use linear_algebra::matrix::Matrix3;
+use linear_algebra::vector::Vector3;
+
+fn dot(a: Vector3<f64>, b: Vector3<f64>) -> f64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+fn check_eigenpairs(matrix: Matrix3<f64>, expected: [f64; 3]) {
+    let eigen = matrix.symmetric_eigen();
+
+    for i in 0..3 {
+        assert!((eigen.eigenvalues[i] - expected[i]).abs() < 1e-12, "{:?}", eigen.eigenvalues);
+
+        let v = eigen.eigenvectors[i];
+        let product = matrix * v;
+        let lambda = eigen.eigenvalues[i];
+        assert!((product.x - lambda * v.x).abs() < 1e-12);
+        assert!((product.y - lambda * v.y).abs() < 1e-12);
+        assert!((product.z - lambda * v.z).abs() < 1e-12);
+
+        for j in 0..3 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((dot(v, eigen.eigenvectors[j]) - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn diagonal() {
+    let matrix = Matrix3::new([
+        [1.0, 0.0, 0.0],
+        [0.0, 5.0, 0.0],
+        [0.0, 0.0, -2.0],
+    ]);
+    check_eigenpairs(matrix, [5.0, 1.0, -2.0]);
+
+    let eigen = matrix.symmetric_eigen();
+    assert_eq!(eigen.eigenvectors[0].y.abs(), 1.0);
+}
+
+#[test]
+fn tridiagonal() {
+    // The eigenvalues of the second difference matrix are 2 - 2cos(kπ/4)
+    let sqrt2 = 2.0f64.sqrt();
+    let matrix = Matrix3::new([
+        [2.0, -1.0, 0.0],
+        [-1.0, 2.0, -1.0],
+        [0.0, -1.0, 2.0],
+    ]);
+    check_eigenpairs(matrix, [2.0 + sqrt2, 2.0, 2.0 - sqrt2]);
+
+    let v = matrix.symmetric_eigen().eigenvectors[1];
+    assert!((v.x.abs() - 0.5f64.sqrt()).abs() < 1e-12);
+    assert!(v.y.abs() < 1e-12);
+}
+
+#[test]
+fn repeated_eigenvalue() {
+    let matrix = Matrix3::new([
+        [2.0, 1.0, 0.0],
+        [1.0, 2.0, 0.0],
+        [0.0, 0.0, 3.0],
+    ]);
+    check_eigenpairs(matrix, [3.0, 3.0, 1.0]);
+}
+
+#[test]
+fn rank_one() {
+    // u * uᵀ has the eigenvalue |u|² along u and zero elsewhere
+    let u = [1.0, 2.0, 2.0];
+    let matrix = Matrix3::new([
+        [u[0] * u[0], u[0] * u[1], u[0] * u[2]],
+        [u[1] * u[0], u[1] * u[1], u[1] * u[2]],
+        [u[2] * u[0], u[2] * u[1], u[2] * u[2]],
+    ]);
+    check_eigenpairs(matrix, [9.0, 0.0, 0.0]);
+
+    let v = matrix.symmetric_eigen().eigenvectors[0];
+    assert!((dot(v, Vector3 { x: 1.0, y: 2.0, z: 2.0 }).abs() - 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn single_precision() {
+    let matrix: Matrix3<f32> = Matrix3::new([
+        [4.0, 1.0, 0.0],
+        [1.0, 4.0, 0.0],
+        [0.0, 0.0, 1.0],
+    ]);
+    let eigen = matrix.symmetric_eigen();
+
+    assert!((eigen.eigenvalues[0] - 5.0).abs() < 1e-5);
+    assert!((eigen.eigenvalues[1] - 3.0).abs() < 1e-5);
+    assert!((eigen.eigenvalues[2] - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn convergence_controls() {
+    let matrix = Matrix3::new([
+        [4.0, 1.0, 2.0],
+        [1.0, 3.0, 0.5],
+        [2.0, 0.5, 1.0],
+    ]);
+
+    let (_, converged) = matrix.symmetric_eigen_with(1e-14, 0);
+    assert!(!converged);
+
+    let (eigen, converged) = matrix.symmetric_eigen_with(1e-14, 10);
+    assert!(converged);
+    let trace = eigen.eigenvalues.iter().sum::<f64>();
+    assert!((trace - 8.0).abs() < 1e-12);
+    assert!((eigen.eigenvalues.iter().product::<f64>() - matrix.determinant()).abs() < 1e-12);
+}
+
+#[test]
+fn nan_does_not_panic() {
+    let matrix = Matrix3::new([
+        [f64::NAN, 1.0, 0.0],
+        [1.0, 2.0, 0.0],
+        [0.0, 0.0, 3.0],
+    ]);
+
+    let (eigen, converged) = matrix.symmetric_eigen_with(1e-14, 5);
+    assert!(!converged);
+    assert!(eigen.eigenvalues.iter().any(|value| value.is_nan()));
+}
@@ -0,0 +1,85 @@
+use linear_algebra::angles::{Deg, Rad};
+use linear_algebra::euler::{EulerAngles, EulerOrder};
+use linear_algebra::quaternion::Quaternion;
+
+mod common;
+
+use common::assert_matrix_eq;
+
+const ORDERS: [EulerOrder; 12] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+    EulerOrder::XYX,
+    EulerOrder::XZX,
+    EulerOrder::YXY,
+    EulerOrder::YZY,
+    EulerOrder::ZXZ,
+    EulerOrder::ZYZ,
+];
+
+fn angles(first: f64, second: f64, third: f64, order: EulerOrder) -> EulerAngles<f64> {
+    EulerAngles::new(Deg(first), Deg(second), Deg(third), order)
+}
+
+#[test]
+fn matrix_round_trip() {
+    for &order in ORDERS.iter() {
+        // Proper euler angles have their middle angle in (0, 180)
+        let second = if order.is_proper() { 50.0 } else { -35.0 };
+        let matrix = angles(20.0, second, 70.0, order).to_matrix3();
+        let (extracted, locked) = EulerAngles::from_matrix3(matrix, order);
+
+        assert!(!locked, "{:?}", order);
+        assert_eq!(extracted.order, order);
+        assert!((Deg::from(extracted.first).0 - 20.0).abs() < 1e-10, "{:?} {:?}", order, extracted);
+        assert!((Deg::from(extracted.second).0 - second).abs() < 1e-10, "{:?} {:?}", order, extracted);
+        assert!((Deg::from(extracted.third).0 - 70.0).abs() < 1e-10, "{:?} {:?}", order, extracted);
+        assert_matrix_eq(extracted.to_matrix3(), matrix, 1e-12);
+    }
+}
+
+#[test]
+fn quaternion_round_trip() {
+    for &order in ORDERS.iter() {
+        let euler = angles(-110.0, 80.0, 15.0, order);
+        let quaternion = euler.to_quaternion();
+        let (extracted, locked) = EulerAngles::from_quaternion(quaternion, order);
+
+        assert!(!locked, "{:?}", order);
+        assert_matrix_eq(quaternion.to_matrix3(), euler.to_matrix3(), 1e-12);
+        assert_matrix_eq(extracted.to_quaternion().to_matrix3(), euler.to_matrix3(), 1e-12);
+        assert!((Quaternion::from_matrix3(euler.to_matrix3()).dot(quaternion).abs() - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn order_is_intrinsic() {
+    let euler = angles(30.0, 40.0, 50.0, EulerOrder::ZYX);
+    let z = angles(30.0, 0.0, 0.0, EulerOrder::ZYX).to_matrix3();
+    let y = angles(0.0, 40.0, 0.0, EulerOrder::ZYX).to_matrix3();
+    let x = angles(0.0, 0.0, 50.0, EulerOrder::ZYX).to_matrix3();
+
+    assert_matrix_eq(euler.to_matrix3(), z * y * x, 1e-12);
+    assert_matrix_eq(euler.to_matrix4().decompose().unwrap().rotation, z * y * x, 1e-12);
+}
+
+#[test]
+fn gimbal_lock() {
+    for &order in ORDERS.iter() {
+        let locked_angles: [f64; 2] = if order.is_proper() { [0.0, 180.0] } else { [90.0, -90.0] };
+
+        for &second in locked_angles.iter() {
+            let matrix = angles(25.0, second, 40.0, order).to_matrix3();
+            let (extracted, locked) = EulerAngles::from_matrix3(matrix, order);
+
+            assert!(locked, "{:?} {}", order, second);
+            assert_eq!(extracted.third, Rad(0.0));
+            assert!((Deg::from(extracted.second).0.abs() - second.abs()).abs() < 1e-6, "{:?} {:?}", order, extracted);
+            assert_matrix_eq(extracted.to_matrix3(), matrix, 1e-12);
+        }
+    }
+}
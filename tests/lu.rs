@@ -0,0 +1,145 @@
+use linear_algebra::error::SolveError;
+use linear_algebra::matrix::{DMatrix, Matrix3, Matrix4, MatrixMN};
+use linear_algebra::vector::{DVector, Vector3, Vector4};
+
+mod common;
+
+use common::assert_dmatrix_eq;
+
+fn hilbert(size: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(size, size, |i, j| 1.0 / (i + j + 1) as f64)
+}
+
+#[test]
+fn factors() {
+    let a: DMatrix<f64> = DMatrix::from_row_major(3, 3, vec![
+        0.0, 2.0, 1.0,
+        4.0, 1.0, -2.0,
+        2.0, 3.0, 5.0,
+    ]).unwrap();
+    let lu = a.lu().unwrap();
+
+    let pa = (&lu.p() * &a).unwrap();
+    let product = (&lu.l() * &lu.u()).unwrap();
+    assert_dmatrix_eq(&pa, &product, 1e-12);
+    assert_eq!(lu.permutation()[0], 1);
+    assert!(!lu.is_singular());
+}
+
+#[test]
+fn solve_matrix3() {
+    let a: Matrix3<f64> = Matrix3::new([
+        [2.0, 1.0, -1.0],
+        [-3.0, -1.0, 2.0],
+        [-2.0, 1.0, 2.0],
+    ]);
+    let x = a.solve(Vector3 { x: 8.0, y: -11.0, z: -3.0 }).unwrap();
+
+    assert!((x.x - 2.0).abs() < 1e-12);
+    assert!((x.y - 3.0).abs() < 1e-12);
+    assert!((x.z + 1.0).abs() < 1e-12);
+    assert!((a.lu().determinant() - a.determinant()).abs() < 1e-12);
+}
+
+#[test]
+fn solve_matrix4_multiple_right_hand_sides() {
+    let a: Matrix4<f64> = Matrix4::new([
+        [1.0, 0.0, 2.0, -1.0],
+        [3.0, 0.0, 0.0, 5.0],
+        [2.0, 1.0, 4.0, -3.0],
+        [1.0, 0.0, 5.0, 0.0],
+    ]);
+    let b = MatrixMN::new([
+        [1.0, 0.0],
+        [2.0, 1.0],
+        [3.0, 0.0],
+        [4.0, 1.0],
+    ]);
+    let x = a.solve_matrix(b).unwrap();
+    let product = a * x;
+
+    for i in 0..4 {
+        for j in 0..2 {
+            assert!((product.values[i][j] - b.values[i][j]).abs() < 1e-12);
+        }
+    }
+    assert!((a.lu().determinant() - 30.0).abs() < 1e-12);
+
+    let v = a.solve(Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 }).unwrap();
+    assert!((v.x - x.values[0][0]).abs() < 1e-12);
+}
+
+#[test]
+fn inverse() {
+    let a: DMatrix<f64> = DMatrix::from_row_major(3, 3, vec![
+        4.0, 7.0, 2.0,
+        3.0, 6.0, 1.0,
+        2.0, 5.0, 3.0,
+    ]).unwrap();
+    let inverse = a.try_inverse().unwrap();
+
+    assert_dmatrix_eq(&(&a * &inverse).unwrap(), &DMatrix::identity(3), 1e-12);
+    assert!((a.determinant().unwrap() - 9.0).abs() < 1e-12);
+}
+
+#[test]
+fn ill_conditioned_hilbert() {
+    // The condition number of the 8x8 Hilbert matrix is around 1e10
+    let a = hilbert(8);
+    let expected = DVector::new(vec![1.0; 8]);
+    let b = (&a * &expected).unwrap();
+    let x = a.solve(&b).unwrap();
+
+    for i in 0..8 {
+        assert!((x[i] - 1.0).abs() < 1e-5, "{:?}", x);
+    }
+    assert!(!a.lu().unwrap().is_singular());
+}
+
+#[test]
+fn needs_pivoting() {
+    // Without pivoting the tiny leading element would destroy the result
+    let a: Matrix3<f64> = Matrix3::new([
+        [1e-20, 1.0, 1.0],
+        [1.0, 1.0, 0.0],
+        [0.0, 1.0, 2.0],
+    ]);
+    let x = a.solve(Vector3 { x: 2.0, y: 2.0, z: 3.0 }).unwrap();
+    let b = a * x;
+
+    assert!((b.x - 2.0).abs() < 1e-12);
+    assert!((b.y - 2.0).abs() < 1e-12);
+    assert!((b.z - 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn singular() {
+    let a: DMatrix<f64> = DMatrix::from_row_major(3, 3, vec![
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        7.0, 8.0, 9.0,
+    ]).unwrap();
+    let lu = a.lu().unwrap();
+
+    assert_eq!(lu.singular_pivot(), Some(2));
+    assert!(lu.determinant().abs() < 1e-12);
+    assert_eq!(a.solve(&DVector::new(vec![1.0, 2.0, 3.0])), Err(SolveError::Singular(2)));
+    assert!(a.try_inverse().is_err());
+}
+
+#[test]
+fn shape_errors() {
+    let a: DMatrix<f64> = DMatrix::zeros(2, 3);
+    assert!(a.lu().is_err());
+    assert!(matches!(hilbert(3).solve(&DVector::zeros(2)), Err(SolveError::Shape(_))));
+}
+
+#[test]
+fn nan_does_not_panic() {
+    let a: DMatrix<f64> = DMatrix::from_row_major(2, 2, vec![
+        f64::NAN, 1.0,
+        2.0, 3.0,
+    ]).unwrap();
+
+    assert!(a.determinant().unwrap().is_nan());
+}
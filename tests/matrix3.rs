@@ -0,0 +1,170 @@
+use linear_algebra::angles::Deg;
+use linear_algebra::matrix::{DMatrix, Matrix3, MatrixMN};
+use linear_algebra::vector::{DVector, Unit, Vector2, Vector3};
+
+use num_traits::{Float, One, Zero};
+
+mod common;
+
+use common::assert_matrix_eq;
+
+#[test]
+fn product_uses_both_operands() {
+    let a = Matrix3::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    let b = Matrix3::new([[9.0, 8.0, 7.0], [6.0, 5.0, 4.0], [3.0, 2.0, 1.0]]);
+    let expected = Matrix3::new([[30.0, 24.0, 18.0], [84.0, 69.0, 54.0], [138.0, 114.0, 90.0]]);
+
+    assert_eq!(a * b, expected);
+    assert_eq!(a * Vector3::new(1.0, 0.0, -1.0), Vector3::new(-2.0, -2.0, -2.0));
+}
+
+#[test]
+fn determinant_and_transpose() {
+    let a = Matrix3::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+
+    assert_eq!(a.determinant(), 6.0);
+    assert_eq!(a.transpose().determinant(), 6.0);
+    assert_eq!(a.transpose().transpose(), a);
+    assert_eq!(a.transpose()[[0, 1]], a[[1, 0]]);
+}
+
+#[test]
+fn inverse_f32() {
+    let a: Matrix3<f32> = Matrix3::new([[4.0, 7.0, 2.0], [3.0, 6.0, 1.0], [2.0, 5.0, 3.0]]);
+    let inverse = a.try_inverse().unwrap();
+
+    assert_matrix_eq(a * inverse, Matrix3::<f32>::identity_matrix(), 1e-5);
+    assert_matrix_eq(inverse * a, Matrix3::<f32>::identity_matrix(), 1e-5);
+}
+
+#[test]
+fn inverse_f64() {
+    let a: Matrix3<f64> = Matrix3::new([[1.5, -2.0, 0.25], [0.0, 3.0, 1.0], [-1.0, 0.5, 2.0]]);
+    let inverse = a.try_inverse().unwrap();
+    let identity = Matrix3::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+    assert_matrix_eq(a * inverse, identity, 1e-12);
+    let mut scaled = inverse;
+    for row in scaled.values.iter_mut() {
+        for element in row.iter_mut() {
+            *element *= a.determinant();
+        }
+    }
+    assert_matrix_eq(a.adjugate(), scaled, 1e-12);
+}
+
+#[test]
+fn singular_has_no_inverse() {
+    let a: Matrix3<f64> = Matrix3::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+
+    assert_eq!(a.determinant(), 0.0);
+    assert!(a.try_inverse().is_none());
+}
+
+fn check_rotation<T: Float + std::fmt::Debug>(epsilon: T) {
+    let (zero, one) = (T::zero(), T::one());
+    let rotation = Matrix3::rotation_matrix(Deg(T::from(90.0).unwrap()), Unit::new_unchecked(Vector3::new(zero, zero, one)));
+    let expected = Matrix3::new([[zero, -one, zero], [one, zero, zero], [zero, zero, one]]);
+
+    assert_matrix_eq(rotation, expected, epsilon);
+    assert_matrix_eq(Matrix3::identity_matrix().rotate(Deg(T::from(90.0).unwrap()), Unit::new_unchecked(Vector3::new(zero, zero, one))), expected, epsilon);
+    assert_matrix_eq(rotation * rotation.transpose(), Matrix3::identity_matrix(), epsilon);
+}
+
+#[test]
+fn rotation_in_both_precisions() {
+    check_rotation::<f32>(1e-6);
+    check_rotation::<f64>(1e-15);
+}
+
+#[test]
+fn constructors() {
+    let diagonal = Matrix3::from_diagonal(Vector3::new(1, 2, 3));
+
+    assert_eq!(diagonal.values, [[1, 0, 0], [0, 2, 0], [0, 0, 3]]);
+    assert_eq!(Matrix3::<u32>::identity(), Matrix3::from_diagonal(Vector3::splat(1)));
+    assert_eq!(Matrix3::<f64>::identity(), Matrix3::identity_matrix());
+    assert_eq!(Matrix3::splat(4).values, [[4; 3]; 3]);
+    assert_eq!(MatrixMN::<i32, 2, 3>::zero().values, [[0; 3]; 2]);
+    assert_eq!(DMatrix::from_diagonal(&DVector::new(vec![1, 2])), DMatrix::from_row_major(2, 2, vec![1, 0, 0, 2]).unwrap());
+}
+
+#[test]
+fn zero_and_one_traits() {
+    let a = Matrix3::new([[1, 1, 0], [0, 1, 0], [0, 0, 2]]);
+
+    assert_eq!(num_traits::pow(a, 3), a * a * a);
+    assert_eq!(num_traits::pow(a, 0), Matrix3::one());
+    assert!(Matrix3::<f32>::zero().is_zero());
+    assert!((Matrix3::<i64>::one() + Matrix3::zero()).is_one());
+}
+
+#[test]
+fn scalar_operators() {
+    let a = Matrix3::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+    assert_eq!(a * 2, a + a);
+    assert_eq!(2 * a, a * 2);
+    assert_eq!((a * 3) / 3, a);
+    assert_eq!(-a, a * -1);
+
+    let mut b = a;
+    b *= 2;
+    b -= a;
+    assert_eq!(b, a);
+}
+
+#[test]
+fn transforms_2d() {
+    let translation = Matrix3::translation_2d(Vector2::new(3, -1));
+    assert_eq!(translation.transform_point2(Vector2::new(1, 1)), Some(Vector2::new(4, 0)));
+    assert_eq!(translation.transform_vector2(Vector2::new(1, 1)), Vector2::new(1, 1));
+    assert_eq!(Matrix3::scaling_2d(Vector2::new(2, 3)).transform_point2(Vector2::new(1, 1)), Some(Vector2::new(2, 3)));
+    assert_eq!(Matrix3::shear_2d(Vector2::new(2, 0)).transform_point2(Vector2::new(1, 1)), Some(Vector2::new(3, 1)));
+
+    let rotation: Matrix3<f64> = Matrix3::rotation_2d(Deg(90.0));
+    let rotated = rotation.transform_point2(Vector2::new(1.0, 0.0)).unwrap();
+    assert!(rotated.x.abs() < 1e-12 && (rotated.y - 1.0).abs() < 1e-12);
+
+    let transform = Matrix3::translation_2d(Vector2::new(5.0, 2.0))
+        * Matrix3::rotation_2d(Deg(30.0))
+        * Matrix3::shear_2d(Vector2::new(0.5, 0.0))
+        * Matrix3::scaling_2d(Vector2::new(2.0, -1.0));
+    let inverse = transform.affine_inverse_2d().unwrap();
+    assert_matrix_eq(transform * inverse, Matrix3::identity(), 1e-12);
+    assert_matrix_eq(inverse, transform.try_inverse().unwrap(), 1e-12);
+    assert!(Matrix3::<f64>::scaling_2d(Vector2::new(1.0, 0.0)).affine_inverse_2d().is_none());
+
+    let mut projective = transform;
+    projective.values[2] = [0.5, 0.0, 1.0];
+    assert!(projective.try_inverse().is_some());
+    assert!(projective.affine_inverse_2d().is_none());
+    // The bottom row gives w = 0.5 * x + 1, which is zero for x = -2
+    assert_eq!(projective.transform_point2(Vector2::new(-2.0, 3.0)), None);
+}
+
+#[test]
+fn rows_and_columns() {
+    let mut a = Matrix3::from_rows([Vector3::new(1, 2, 3), Vector3::new(4, 5, 6), Vector3::new(7, 8, 9)]);
+
+    assert_eq!(a, Matrix3::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]));
+    assert_eq!(a.get(0, 2), Some(3));
+    assert_eq!(a.get(0, 2), Some(a[[0, 2]]));
+    assert_eq!(a[[1, 0]], 4);
+    assert_eq!(a.get(3, 0), None);
+    assert_eq!(Vector3::from(a.row(1)), Vector3::new(4, 5, 6));
+    assert_eq!(Vector3::from(a.col(1)), Vector3::new(2, 5, 8));
+    assert_eq!(Matrix3::from_cols([a.col(0), a.col(1), a.col(2)]), a);
+    assert_eq!(Matrix3::from_cols([a.row(0), a.row(1), a.row(2)]), a.transpose());
+
+    assert_eq!(a.as_row_major_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(a.to_cols_array(), [[1, 4, 7], [2, 5, 8], [3, 6, 9]]);
+
+    a.set_row(0, Vector3::new(0, 0, 0));
+    a.set_col(2, Vector3::new(1, 1, 1));
+    assert_eq!(a, Matrix3::new([[0, 0, 1], [4, 5, 1], [7, 8, 1]]));
+
+    let wide: MatrixMN<i32, 2, 3> = MatrixMN::from_cols([[1, 4], [2, 5], [3, 6]]);
+    assert_eq!(wide.as_row_major_slice(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(wide.to_cols_array(), [[1, 4], [2, 5], [3, 6]]);
+}
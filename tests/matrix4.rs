@@ -0,0 +1,271 @@
+use linear_algebra::angles::Deg;
+use linear_algebra::matrix::Matrix4;
+use linear_algebra::quaternion::Quaternion;
+use linear_algebra::vector::{Unit, Vector3, Vector4};
+
+use num_traits::Float;
+
+mod common;
+
+use common::assert_matrix_eq;
+
+fn identity() -> Matrix4<f64> {
+    Matrix4::new([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+#[test]
+fn determinant() {
+    let a: Matrix4<f64> = Matrix4::new([
+        [1.0, 0.0, 2.0, -1.0],
+        [3.0, 0.0, 0.0, 5.0],
+        [2.0, 1.0, 4.0, -3.0],
+        [1.0, 0.0, 5.0, 0.0],
+    ]);
+
+    assert_eq!(a.determinant(), 30.0);
+    assert_eq!(identity().determinant(), 1.0);
+}
+
+#[test]
+fn inverse() {
+    let a: Matrix4<f64> = Matrix4::new([
+        [2.0, -1.0, 0.5, 3.0],
+        [0.0, 4.0, 1.0, -2.0],
+        [1.5, 0.0, 3.0, 1.0],
+        [-1.0, 2.0, 0.0, 5.0],
+    ]);
+    let inverse = a.try_inverse().unwrap();
+
+    assert_matrix_eq(a * inverse, identity(), 1e-12);
+    assert_matrix_eq(inverse * a, identity(), 1e-12);
+}
+
+#[test]
+fn singular_has_no_inverse() {
+    let a: Matrix4<f64> = Matrix4::new([
+        [1.0, 2.0, 3.0, 4.0],
+        [2.0, 4.0, 6.0, 8.0],
+        [0.0, 1.0, 0.0, 1.0],
+        [1.0, 0.0, 1.0, 0.0],
+    ]);
+
+    assert!(a.try_inverse().is_none());
+}
+
+#[test]
+fn affine_inverse() {
+    let a: Matrix4<f64> = Matrix4::new([
+        [2.0, 0.0, 1.0, 4.0],
+        [0.0, 3.0, 0.0, -2.0],
+        [-1.0, 0.0, 0.5, 7.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    let inverse = a.affine_inverse().unwrap();
+
+    assert_matrix_eq(a * inverse, identity(), 1e-12);
+    assert_matrix_eq(inverse, a.try_inverse().unwrap(), 1e-12);
+
+    let transform = Matrix4::<f32>::identity_matrix()
+        .translate(Vector3::new(1.0, 2.0, 3.0))
+        .scale(Vector3::new(2.0, 4.0, 0.5));
+    let undone = transform * transform.affine_inverse().unwrap();
+    assert_eq!(undone, Matrix4::<f32>::identity_matrix());
+}
+
+#[test]
+fn look_at_rh() {
+    let view: Matrix4<f64> = Matrix4::look_at_rh(
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    let expected = Matrix4::new([
+        [0.0, 0.0, -1.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0, -1.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    assert_matrix_eq(view, expected, 1e-12);
+}
+
+#[test]
+fn look_at_lh() {
+    let view: Matrix4<f64> = Matrix4::look_at_lh(
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    let expected = Matrix4::new([
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [-1.0, 0.0, 0.0, 1.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    assert_matrix_eq(view, expected, 1e-12);
+}
+
+#[test]
+fn look_to_moves_target_in_front_of_camera() {
+    let eye = Vector3::new(1.0, 2.0, 3.0);
+    let direction = Vector3::new(2.0, -1.0, 2.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    let target = Vector4::new(eye.x + direction.x, eye.y + direction.y, eye.z + direction.z, 1.0);
+
+    let rh: Matrix4<f64> = Matrix4::look_to_rh(eye, direction, up);
+    let lh: Matrix4<f64> = Matrix4::look_to_lh(eye, direction, up);
+    let (rh_target, lh_target) = (rh * target, lh * target);
+
+    assert!(rh_target.x.abs() < 1e-12 && rh_target.y.abs() < 1e-12);
+    assert!((rh_target.z + 3.0).abs() < 1e-12);
+    assert!(lh_target.x.abs() < 1e-12 && lh_target.y.abs() < 1e-12);
+    assert!((lh_target.z - 3.0).abs() < 1e-12);
+    assert_matrix_eq(rh.affine_inverse().unwrap() * rh, identity(), 1e-12);
+}
+
+fn check_transforms<T: Float + std::fmt::Debug>(epsilon: T) {
+    let (zero, one, two) = (T::zero(), T::one(), T::from(2.0).unwrap());
+    let point = Vector4::new(one, zero, zero, one);
+    let transform = Matrix4::identity_matrix()
+        .translate(Vector3::new(one, two, zero))
+        .rotate(Deg(T::from(90.0).unwrap()), Unit::new_unchecked(Vector3::new(zero, zero, one)))
+        .scale(Vector3::new(two, one, one));
+    let moved = transform * point;
+
+    assert!((moved.x - one).abs() <= epsilon);
+    assert!((moved.y - T::from(4.0).unwrap()).abs() <= epsilon);
+    assert!(moved.z.abs() <= epsilon);
+    assert_eq!(moved.w, one);
+
+    let translation = Matrix4::translation_matrix(Vector3::new(one, two, two));
+    assert_eq!(translation * Matrix4::translation_matrix(Vector3::new(-one, -two, -two)), Matrix4::identity_matrix());
+    assert_eq!(Matrix4::scaling_matrix(Vector3::new(two, two, two)).determinant(), T::from(8.0).unwrap());
+}
+
+#[test]
+fn transforms_in_both_precisions() {
+    check_transforms::<f32>(1e-6);
+    check_transforms::<f64>(1e-15);
+}
+
+#[test]
+fn integer_identity_and_translation() {
+    let translation = Matrix4::<i32>::translation_matrix(Vector3::new(1, 2, 3));
+
+    assert_eq!(translation * Vector4::new(1, 1, 1, 1), Vector4::new(2, 3, 4, 1));
+    assert_eq!(Matrix4::<u32>::identity_matrix() * Vector4::new(1, 2, 3, 4), Vector4::new(1, 2, 3, 4));
+}
+
+#[test]
+fn scalar_operators() {
+    let a = Matrix4::<f64>::identity() * 2.0;
+
+    assert_eq!(a.determinant(), 16.0);
+    assert_eq!(0.5 * a, identity());
+    assert_eq!(a / 2.0, identity());
+    assert_eq!(-a + a, Matrix4::zero());
+
+    let mut b = identity();
+    b += identity();
+    b *= 3.0;
+    b -= identity();
+    b /= 5.0;
+    assert_eq!(b, identity());
+
+    let mut c = Matrix4::translation_matrix(Vector3::new(1, 2, 3));
+    c *= Matrix4::translation_matrix(Vector3::new(1, 1, 1));
+    assert_eq!(c, Matrix4::translation_matrix(Vector3::new(2, 3, 4)));
+    assert_eq!(2 * Matrix4::<i32>::identity(), Matrix4::from_diagonal(Vector4::splat(2)));
+}
+
+#[test]
+fn decompose_round_trip() {
+    let axis = Unit::new_normalize(Vector3::new(1.0, 2.0, -1.0));
+    let transform: Matrix4<f32> = Matrix4::identity()
+        .translate(Vector3::new(1.0, -2.0, 3.0))
+        .rotate(Deg(30.0), axis)
+        .scale(Vector3::new(2.0, 0.5, 3.0));
+    let parts = transform.decompose().unwrap();
+
+    assert_eq!(parts.translation, Vector3::new(1.0, -2.0, 3.0));
+    assert!((parts.scale - Vector3::new(2.0, 0.5, 3.0)).magnitude() < 1e-5);
+    assert!(parts.shear.magnitude() < 1e-5);
+    let expected = Quaternion::from_axis_angle(axis, Deg(30.0f32));
+    assert!((parts.quaternion.dot(expected).abs() - 1.0).abs() < 1e-5);
+
+    let composed = Matrix4::from_trs(parts.translation, parts.quaternion, parts.scale);
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((composed[[i, j]] - transform[[i, j]]).abs() < 1e-5, "{:?} != {:?}", composed, transform);
+        }
+    }
+}
+
+#[test]
+fn decompose_negative_scale() {
+    let rotation = Quaternion::from_axis_angle(Unit::new_unchecked(Vector3::new(0.0, 1.0, 0.0)), Deg(45.0));
+    let transform: Matrix4<f64> = Matrix4::from_trs(Vector3::new(0.0, 1.0, 0.0), rotation, Vector3::new(-2.0, 3.0, 4.0));
+    let parts = transform.decompose().unwrap();
+
+    assert!((parts.scale - Vector3::new(-2.0, 3.0, 4.0)).magnitude() < 1e-12);
+    assert!((parts.quaternion.dot(rotation) - 1.0).abs() < 1e-12);
+    assert!((parts.rotation.determinant() - 1.0).abs() < 1e-12);
+
+    // A mirrored y axis is reported as a mirrored x axis with a different rotation
+    let mirrored: Matrix4<f64> = Matrix4::from_trs(Vector3::zero(), rotation, Vector3::new(2.0, -3.0, 4.0));
+    let parts = mirrored.decompose().unwrap();
+    assert!(parts.scale.x < 0.0);
+    assert!((parts.rotation.determinant() - 1.0).abs() < 1e-12);
+    assert_matrix_eq(Matrix4::from_trs(parts.translation, parts.quaternion, parts.scale), mirrored, 1e-12);
+}
+
+#[test]
+fn decompose_shear() {
+    let shear: Matrix4<f64> = Matrix4::new([
+        [1.0, 0.5, 0.25, 0.0],
+        [0.0, 1.0, -1.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    let transform = Matrix4::identity()
+        .translate(Vector3::new(4.0, 5.0, 6.0))
+        .rotate(Deg(60.0), Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0)))
+        * shear
+        * Matrix4::scaling_matrix(Vector3::new(-1.0, 2.0, 3.0));
+    let parts = transform.decompose().unwrap();
+
+    assert!((parts.shear - Vector3::new(0.5, 0.25, -1.0)).magnitude() < 1e-12);
+    assert!((parts.scale - Vector3::new(-1.0, 2.0, 3.0)).magnitude() < 1e-12);
+    assert_matrix_eq(parts.to_matrix(), transform, 1e-12);
+}
+
+#[test]
+fn decompose_degenerate() {
+    let flat: Matrix4<f64> = Matrix4::scaling_matrix(Vector3::new(1.0, 0.0, 1.0));
+    let projective: Matrix4<f64> = Matrix4::new([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+    ]);
+
+    assert!(flat.decompose().is_none());
+    assert!(projective.decompose().is_none());
+}
+
+#[test]
+fn translation_in_last_column() {
+    let translation = Matrix4::translation_matrix(Vector3::new(1, 2, 3));
+
+    assert_eq!(Vector4::from(translation.col(3)), Vector4::new(1, 2, 3, 1));
+    assert_eq!(Vector4::from(translation.row(3)), Vector4::new(0, 0, 0, 1));
+    assert_eq!(translation[[0, 3]], 1);
+    assert_eq!(translation.to_cols_array()[3], [1, 2, 3, 1]);
+    assert_eq!(&translation.to_cols_array().as_flattened()[12..15], &[1, 2, 3]);
+}
@@ -0,0 +1,38 @@
+use linear_algebra::matrix::MatrixMN;
+use linear_algebra::vector::VectorN;
+
+#[test]
+fn non_square_product() {
+    let a: MatrixMN<i32, 3, 4> = MatrixMN::from_rows([
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [9, 10, 11, 12],
+    ]);
+    let b: MatrixMN<i32, 4, 2> = MatrixMN::from_rows([
+        [1, 0],
+        [0, 1],
+        [1, 1],
+        [2, -1],
+    ]);
+    let product: MatrixMN<i32, 3, 2> = a * b;
+
+    assert_eq!(product.values, [[12, 1], [28, 5], [44, 9]]);
+    assert_eq!((a * b).transpose().values, (b.transpose() * a.transpose()).values);
+}
+
+#[test]
+fn non_square_times_vector() {
+    let a: MatrixMN<i32, 2, 3> = MatrixMN::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let product: VectorN<i32, 2> = a * VectorN::new([1, 0, -1]);
+
+    assert_eq!(product.values, [-2, -2]);
+}
+
+#[test]
+fn display() {
+    let wide: MatrixMN<i32, 2, 3> = MatrixMN::from_rows([[1, -20, 3], [4, 5, 600]]);
+    let row: MatrixMN<i32, 1, 2> = MatrixMN::from_rows([[7, 8]]);
+
+    assert_eq!(wide.to_string(), "\n⎡1 -20  3⎤\n⎣4  5  600⎦\n");
+    assert_eq!(row.to_string(), "\n[7 8]\n");
+}
@@ -0,0 +1,78 @@
+use linear_algebra::angles::Deg;
+use linear_algebra::matrix::Matrix4;
+use linear_algebra::point::Point3;
+use linear_algebra::vector::{Unit, Vector3, Vector4};
+
+#[test]
+fn point_arithmetic() {
+    let a = Point3::new(1, 2, 3);
+    let b = Point3::new(4, 6, 3);
+
+    assert_eq!(b - a, Vector3::new(3, 4, 0));
+    assert_eq!(a + Vector3::new(3, 4, 0), b);
+    assert_eq!(b - Vector3::new(3, 4, 0), a);
+    assert_eq!(a.distance_squared(b), 25);
+
+    let mut c = Point3::origin();
+    c += Vector3::new(1, 1, 1);
+    c -= Vector3::new(0, 1, 2);
+    assert_eq!(c, Point3::new(1, 0, -1));
+    assert_eq!(c[2], -1);
+    assert_eq!(Point3::from_vector(c.to_vector()), c);
+}
+
+#[test]
+fn float_helpers() {
+    let a: Point3<f64> = Point3::new(0.0, 0.0, 0.0);
+    let b = Point3::new(3.0, 4.0, 0.0);
+
+    assert_eq!(a.distance(b), 5.0);
+    assert_eq!(a.lerp(b, 0.5), Point3::new(1.5, 2.0, 0.0));
+}
+
+#[test]
+fn homogeneous() {
+    let point = Point3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(point.to_homogeneous(), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(Point3::from_homogeneous(Vector4::new(2.0, 4.0, 6.0, 2.0)), Some(point));
+    assert_eq!(Point3::from_homogeneous(Vector4::new(2.0, 4.0, 6.0, 0.0)), None);
+}
+
+#[test]
+fn transforms() {
+    let translation = Matrix4::translation_matrix(Vector3::new(1, 2, 3));
+
+    assert_eq!(translation.transform_point(Point3::new(1, 1, 1)), Some(Point3::new(2, 3, 4)));
+    assert_eq!(translation * Point3::new(1, 1, 1), Some(Point3::new(2, 3, 4)));
+    assert_eq!(translation.transform_vector(Vector3::new(1, 1, 1)), Vector3::new(1, 1, 1));
+
+    let rotation: Matrix4<f64> = Matrix4::rotation_matrix(Deg(90.0), Unit::new_normalize(Vector3::new(0.0, 0.0, 1.0)));
+    let direction = rotation.transform_vector(Vector3::new(1.0, 0.0, 0.0));
+    assert!((direction - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-12);
+}
+
+#[test]
+fn perspective_divide() {
+    // Projects onto the plane z = 1 by copying z into w
+    let projection: Matrix4<f64> = Matrix4::new([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+    ]);
+
+    assert_eq!(projection.transform_point(Point3::new(2.0, 4.0, 2.0)), Some(Point3::new(1.0, 2.0, 1.0)));
+    // A point on the camera plane has no projection
+    assert_eq!(projection.transform_point(Point3::new(2.0, 4.0, 0.0)), None);
+    assert_eq!(projection * Point3::new(1.0, 0.0, 0.0), None);
+
+    let integer_projection: Matrix4<i32> = Matrix4::new([
+        [1, 0, 0, 0],
+        [0, 1, 0, 0],
+        [0, 0, 1, 0],
+        [0, 0, 1, 0],
+    ]);
+    assert_eq!(integer_projection.transform_point(Point3::new(2, 4, 0)), None);
+    assert_eq!(integer_projection.transform_point(Point3::new(2, 4, 2)), Some(Point3::new(1, 2, 1)));
+}
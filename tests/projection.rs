@@ -0,0 +1,122 @@
+use linear_algebra::angles::Deg;
+use linear_algebra::matrix::Matrix4;
+use linear_algebra::matrix::projection::{ClipSpace, DepthRange};
+use linear_algebra::point::Point3;
+
+const CLIP_SPACES: [ClipSpace; 3] = [ClipSpace::OPENGL, ClipSpace::VULKAN, ClipSpace::DIRECTX];
+const NEAR: f64 = 0.5;
+const FAR: f64 = 200.0;
+
+fn depth_bounds(clip: ClipSpace) -> (f64, f64) {
+    match clip.depth {
+        DepthRange::NegativeOneToOne => (-1.0, 1.0),
+        DepthRange::ZeroToOne => (0.0, 1.0),
+    }
+}
+
+/// Returns the depth in normalized device coordinates of a point at the given distance in front of the camera
+fn depth(projection: Matrix4<f64>, distance: f64) -> f64 {
+    projection.transform_point(Point3::new(0.0, 0.0, -distance)).unwrap().z
+}
+
+fn assert_close(a: f64, b: f64, epsilon: f64) {
+    assert!((a - b).abs() <= epsilon, "{} != {}", a, b);
+}
+
+#[test]
+fn perspective_depth() {
+    for &clip in CLIP_SPACES.iter() {
+        let (depth_near, depth_far) = depth_bounds(clip);
+        let projection = Matrix4::perspective(Deg(60.0), 16.0 / 9.0, NEAR, FAR, clip);
+
+        assert_close(depth(projection, NEAR), depth_near, 1e-12);
+        assert_close(depth(projection, FAR), depth_far, 1e-12);
+        assert!(depth(projection, 10.0) > depth_near && depth(projection, 10.0) < depth_far);
+    }
+}
+
+#[test]
+fn perspective_infinite_depth() {
+    for &clip in CLIP_SPACES.iter() {
+        let (depth_near, depth_far) = depth_bounds(clip);
+        let projection = Matrix4::perspective_infinite(Deg(60.0), 1.5, NEAR, clip);
+
+        assert_close(depth(projection, NEAR), depth_near, 1e-12);
+        assert_close(depth(projection, 1e12), depth_far, 1e-9);
+        assert!(depth(projection, 1e12) < depth_far);
+    }
+}
+
+#[test]
+fn perspective_reversed_z_depth() {
+    for &clip in CLIP_SPACES.iter() {
+        let (depth_near, depth_far) = depth_bounds(clip);
+        let projection = Matrix4::perspective_reversed_z(Deg(60.0), 1.5, NEAR, FAR, clip);
+        let infinite = Matrix4::perspective_infinite_reversed_z(Deg(60.0), 1.5, NEAR, clip);
+
+        // Near maps to the largest depth, 1, and far to the smallest
+        assert_close(depth(projection, NEAR), 1.0, 1e-12);
+        assert_close(depth(projection, FAR), depth_near, 1e-12);
+        assert_close(depth(infinite, NEAR), depth_far, 1e-12);
+        assert_close(depth(infinite, 1e12), depth_near, 1e-9);
+        assert!(depth(infinite, 1e12) > depth_near);
+    }
+
+    let projection = Matrix4::perspective_reversed_z(Deg(60.0), 1.5, NEAR, FAR, ClipSpace::DIRECTX);
+    assert_close(depth(projection, NEAR), 1.0, 1e-12);
+    assert_close(depth(projection, FAR), 0.0, 1e-12);
+}
+
+#[test]
+fn orthographic_depth_and_extent() {
+    for &clip in CLIP_SPACES.iter() {
+        let (depth_near, depth_far) = depth_bounds(clip);
+        let projection = Matrix4::orthographic(-4.0, 2.0, -1.0, 3.0, NEAR, FAR, clip);
+        let y_sign = if clip.flip_y { -1.0 } else { 1.0 };
+
+        let near_corner = projection.transform_point(Point3::new(-4.0, -1.0, -NEAR)).unwrap();
+        let far_corner = projection.transform_point(Point3::new(2.0, 3.0, -FAR)).unwrap();
+        assert_close(near_corner.x, -1.0, 1e-12);
+        assert_close(near_corner.y, -y_sign, 1e-12);
+        assert_close(near_corner.z, depth_near, 1e-12);
+        assert_close(far_corner.x, 1.0, 1e-12);
+        assert_close(far_corner.y, y_sign, 1e-12);
+        assert_close(far_corner.z, depth_far, 1e-12);
+    }
+}
+
+#[test]
+fn flip_y() {
+    let point = Point3::new(0.5, 1.0, -5.0);
+    let projections = |clip| [
+        Matrix4::perspective(Deg(60.0), 1.0, NEAR, FAR, clip),
+        Matrix4::perspective_infinite(Deg(60.0), 1.0, NEAR, clip),
+        Matrix4::perspective_reversed_z(Deg(60.0), 1.0, NEAR, FAR, clip),
+        Matrix4::perspective_infinite_reversed_z(Deg(60.0), 1.0, NEAR, clip),
+        Matrix4::orthographic(-2.0, 2.0, -2.0, 2.0, NEAR, FAR, clip),
+    ];
+
+    // Vulkan and DirectX only differ in the direction of y
+    for (flipped, unflipped) in projections(ClipSpace::VULKAN).iter().zip(projections(ClipSpace::DIRECTX).iter()) {
+        let (a, b) = (flipped.transform_point(point).unwrap(), unflipped.transform_point(point).unwrap());
+
+        assert!(b.y > 0.0);
+        assert_close(a.y, -b.y, 1e-12);
+        assert_close(a.x, b.x, 1e-12);
+        assert_close(a.z, b.z, 1e-12);
+    }
+    for projection in projections(ClipSpace::OPENGL).iter() {
+        assert!(projection.transform_point(point).unwrap().y > 0.0);
+    }
+}
+
+#[test]
+fn camera_plane_has_no_projection() {
+    for &clip in CLIP_SPACES.iter() {
+        let projection = Matrix4::perspective(Deg(60.0), 1.0, NEAR, FAR, clip);
+
+        assert_eq!(projection.transform_point(Point3::new(1.0, 2.0, 0.0)), None);
+        assert_eq!(projection * Point3::new(0.0, 0.0, 0.0), None);
+        assert!(projection.transform_point(Point3::new(1.0, 2.0, -NEAR)).is_some());
+    }
+}
@@ -0,0 +1,146 @@
+use linear_algebra::decomposition::{gram_schmidt, least_squares, Qr};
+use linear_algebra::error::SolveError;
+use linear_algebra::matrix::{DMatrix, Matrix3, MatrixMN};
+use linear_algebra::vector::{DVector, Vector3};
+
+mod common;
+
+use common::assert_dmatrix_eq;
+
+fn sample_matrix() -> DMatrix<f64> {
+    DMatrix::from_row_major(4, 3, vec![
+        12.0, -51.0, 4.0,
+        6.0, 167.0, -68.0,
+        -4.0, 24.0, -41.0,
+        1.0, 2.0, 3.0,
+    ]).unwrap()
+}
+
+fn check_factors(qr: &Qr<f64>, a: &DMatrix<f64>) {
+    let q = qr.q();
+    let r = qr.r();
+    assert_dmatrix_eq(&(q * r).unwrap(), a, 1e-10);
+    assert_dmatrix_eq(&(&q.transpose() * q).unwrap(), &DMatrix::identity(q.columns()), 1e-12);
+    for i in 0..r.rows() {
+        for j in 0..i {
+            assert_eq!(r[[i, j]], 0.0);
+        }
+    }
+}
+
+#[test]
+fn householder() {
+    let a = sample_matrix();
+    let qr = a.qr();
+
+    assert_eq!(qr.q().shape(), [4, 3]);
+    assert_eq!(qr.r().shape(), [3, 3]);
+    check_factors(&qr, &a);
+}
+
+#[test]
+fn gram_schmidt_decomposition() {
+    let a = sample_matrix();
+    check_factors(&Qr::gram_schmidt(&a), &a);
+}
+
+#[test]
+fn wide_matrix() {
+    let a = sample_matrix().transpose();
+    let qr = a.qr();
+
+    assert_eq!(qr.q().shape(), [3, 3]);
+    assert_eq!(qr.r().shape(), [3, 4]);
+    check_factors(&qr, &a);
+}
+
+#[test]
+fn matrix3() {
+    let a: Matrix3<f64> = Matrix3::new([
+        [2.0, -1.0, 0.0],
+        [1.0, 3.0, 1.0],
+        [0.0, 1.0, 4.0],
+    ]);
+    check_factors(&a.qr(), &DMatrix::from(a));
+
+    let (x, residual) = a.least_squares(Vector3 { x: 1.0, y: 5.0, z: 9.0 }).unwrap();
+    let b = a * Vector3::from(x);
+    assert!((b.x - 1.0).abs() < 1e-12 && (b.y - 5.0).abs() < 1e-12 && (b.z - 9.0).abs() < 1e-12);
+    assert!(residual < 1e-12);
+}
+
+#[test]
+fn plane_fit() {
+    // Samples of z = 2x - 3y + 1 with some noise
+    let samples = [
+        (0.0, 0.0, 1.1),
+        (1.0, 0.0, 2.9),
+        (0.0, 1.0, -2.1),
+        (1.0, 1.0, 0.1),
+        (2.0, 1.0, 1.9),
+        (1.0, 2.0, -3.0),
+    ];
+    let a: DMatrix<f64> = DMatrix::from_fn(samples.len(), 3, |i, j| match j {
+        0 => samples[i].0,
+        1 => samples[i].1,
+        _ => 1.0,
+    });
+    let b = DVector::new(samples.iter().map(|sample| sample.2).collect());
+    let (x, residual) = least_squares(&a, &b).unwrap();
+
+    assert!((x[0] - 2.0).abs() < 0.1);
+    assert!((x[1] + 3.0).abs() < 0.1);
+    assert!((x[2] - 1.0).abs() < 0.1);
+
+    let difference = (&(&a * &x).unwrap() - &b).unwrap();
+    assert!((difference.dot(&difference).unwrap().sqrt() - residual).abs() < 1e-12);
+    assert!(residual > 0.0);
+}
+
+#[test]
+fn exact_line_fit() {
+    let a: MatrixMN<f64, 4, 2> = MatrixMN::new([
+        [0.0, 1.0],
+        [1.0, 1.0],
+        [2.0, 1.0],
+        [3.0, 1.0],
+    ]);
+    let (x, residual) = a.least_squares([1.0, 3.0, 5.0, 7.0]).unwrap();
+
+    assert!((x[0] - 2.0).abs() < 1e-12);
+    assert!((x[1] - 1.0).abs() < 1e-12);
+    assert!(residual < 1e-12);
+}
+
+#[test]
+fn rank_deficient() {
+    let a: DMatrix<f64> = DMatrix::from_row_major(3, 2, vec![
+        1.0, 2.0,
+        2.0, 4.0,
+        3.0, 6.0,
+    ]).unwrap();
+    let b = DVector::new(vec![1.0, 2.0, 3.0]);
+
+    assert_eq!(a.least_squares(&b), Err(SolveError::Singular(1)));
+    assert!(matches!(a.transpose().least_squares(&DVector::new(vec![1.0, 2.0])), Err(SolveError::Shape(_))));
+}
+
+#[test]
+fn gram_schmidt_vectors() {
+    let vectors = [
+        Vector3 { x: 1.0, y: 1.0, z: 0.0 },
+        Vector3 { x: 2.0, y: 2.0, z: 0.0 },
+        Vector3 { x: 1.0, y: 0.0, z: 1.0 },
+        Vector3 { x: 0.0, y: 1.0, z: 1.0 },
+    ];
+    let basis: Vec<Vector3<f64>> = gram_schmidt(&vectors);
+
+    assert_eq!(basis.len(), 3);
+    for i in 0..3 {
+        for j in 0..3 {
+            let dot = basis[i].x * basis[j].x + basis[i].y * basis[j].y + basis[i].z * basis[j].z;
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((dot - expected).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use linear_algebra::angles::Deg;
+use linear_algebra::matrix::Matrix3;
+use linear_algebra::quaternion::Quaternion;
+use linear_algebra::vector::{Unit, Vector3};
+
+mod common;
+
+use common::assert_matrix_eq;
+
+fn axis(x: f64, y: f64, z: f64) -> Unit<Vector3<f64>> {
+    Unit::new_normalize(Vector3::new(x, y, z))
+}
+
+fn assert_quaternion_eq(a: Quaternion<f64>, b: Quaternion<f64>, epsilon: f64) {
+    assert!((a - b).magnitude() <= epsilon, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn interpolation_end_points_and_middle() {
+    let start = Quaternion::identity();
+    let end = Quaternion::from_axis_angle(axis(0.0, 0.0, 1.0), Deg(90.0));
+    let middle = Quaternion::from_axis_angle(axis(0.0, 0.0, 1.0), Deg(45.0));
+
+    assert_quaternion_eq(start.slerp(end, 0.0), start, 1e-12);
+    assert_quaternion_eq(start.slerp(end, 0.5), middle, 1e-12);
+    assert_quaternion_eq(start.slerp(end, 1.0), end, 1e-12);
+
+    assert_quaternion_eq(start.nlerp(end, 0.0), start, 1e-12);
+    assert_quaternion_eq(start.nlerp(end, 0.5), middle, 1e-12);
+    assert_quaternion_eq(start.nlerp(end, 1.0), end, 1e-12);
+}
+
+#[test]
+fn interpolation_takes_shortest_path() {
+    let start = Quaternion::identity();
+    // The same rotation as 90 degrees around z, but in the opposite hemisphere
+    let end = -Quaternion::from_axis_angle(axis(0.0, 0.0, 1.0), Deg(90.0));
+    let middle = Quaternion::from_axis_angle(axis(0.0, 0.0, 1.0), Deg(45.0));
+
+    assert!(start.dot(end) < 0.0);
+    assert_quaternion_eq(start.slerp(end, 0.5), middle, 1e-12);
+    assert_quaternion_eq(start.nlerp(end, 0.5), middle, 1e-12);
+    assert_quaternion_eq(start.slerp(end, 1.0), -end, 1e-12);
+}
+
+#[test]
+fn matrix_round_trip_in_every_branch() {
+    let rotations = [
+        // Positive trace
+        Quaternion::from_axis_angle(axis(1.0, 2.0, 3.0), Deg(30.0)),
+        // Negative trace with the largest diagonal element in x, y and z
+        Quaternion::from_axis_angle(axis(1.0, 0.1, 0.2), Deg(170.0)),
+        Quaternion::from_axis_angle(axis(0.1, 1.0, 0.2), Deg(170.0)),
+        Quaternion::from_axis_angle(axis(0.1, 0.2, 1.0), Deg(170.0)),
+    ];
+
+    for rotation in rotations.iter() {
+        let matrix = rotation.to_matrix3();
+        let converted = Quaternion::from_matrix3(matrix);
+
+        // q and -q are the same rotation
+        let sign = if converted.dot(*rotation) < 0.0 { -1.0 } else { 1.0 };
+        assert_quaternion_eq(converted * sign, *rotation, 1e-12);
+        assert_matrix_eq(converted.to_matrix3(), matrix, 1e-12);
+    }
+}
+
+#[test]
+fn rotate_vector_matches_rotation_matrix() {
+    let axis = axis(-1.0, 2.0, 0.5);
+    let rotation = Quaternion::from_axis_angle(axis, Deg(130.0));
+    let matrix = Matrix3::rotation_matrix(Deg(130.0), axis);
+    let vector = Vector3::new(0.3, -2.0, 1.5);
+
+    assert!((rotation.rotate_vector(vector) - matrix * vector).magnitude() < 1e-12);
+    assert_matrix_eq(rotation.to_matrix3(), matrix, 1e-12);
+}
+
+#[test]
+fn inverse() {
+    let rotation = Quaternion::from_axis_angle(axis(1.0, 1.0, 0.0), Deg(60.0)) * 2.0;
+
+    assert_quaternion_eq(rotation * rotation.try_inverse().unwrap(), Quaternion::identity(), 1e-12);
+    assert!(Quaternion::new(0.0, 0.0, 0.0, 0.0).try_inverse().is_none());
+}
@@ -0,0 +1,149 @@
+use linear_algebra::decomposition::Svd;
+use linear_algebra::matrix::{DMatrix, Matrix3, Matrix4};
+use linear_algebra::vector::Vector3;
+
+use std::convert::TryFrom;
+
+mod common;
+
+use common::assert_dmatrix_eq;
+
+fn check_factors(svd: &Svd<f64>, a: &DMatrix<f64>) {
+    let (u, v_t) = (svd.u(), svd.v_t());
+    let product = (&(u * &svd.sigma()).unwrap() * v_t).unwrap();
+
+    assert_dmatrix_eq(&product, a, 1e-10);
+    assert_dmatrix_eq(&(&u.transpose() * u).unwrap(), &DMatrix::identity(u.columns()), 1e-12);
+    assert_dmatrix_eq(&(v_t * &v_t.transpose()).unwrap(), &DMatrix::identity(v_t.rows()), 1e-12);
+
+    let values = &svd.singular_values().values;
+    assert!(values.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert!(values.iter().all(|value| *value >= 0.0));
+}
+
+fn sample_matrix() -> DMatrix<f64> {
+    DMatrix::from_row_major(4, 3, vec![
+        3.0, 2.0, 2.0,
+        2.0, 3.0, -2.0,
+        1.0, 0.0, 4.0,
+        -1.0, 5.0, 1.0,
+    ]).unwrap()
+}
+
+#[test]
+fn tall_and_wide() {
+    let a = sample_matrix();
+    check_factors(&a.svd(), &a);
+
+    let a = a.transpose();
+    let svd = a.svd();
+    assert_eq!(svd.u().shape(), [3, 3]);
+    assert_eq!(svd.v_t().shape(), [3, 4]);
+    check_factors(&svd, &a);
+}
+
+#[test]
+fn known_singular_values() {
+    // The singular values of [[3, 2, 2], [2, 3, -2]] are 5 and 3
+    let a: DMatrix<f64> = DMatrix::from_row_major(2, 3, vec![3.0, 2.0, 2.0, 2.0, 3.0, -2.0]).unwrap();
+    let svd = a.svd();
+
+    assert!((svd.singular_values()[0] - 5.0).abs() < 1e-12);
+    assert!((svd.singular_values()[1] - 3.0).abs() < 1e-12);
+    assert!((a.condition_number() - 5.0 / 3.0).abs() < 1e-12);
+    check_factors(&svd, &a);
+}
+
+#[test]
+fn singular_condition_number() {
+    assert_eq!(DMatrix::<f64>::zeros(3, 2).condition_number(), f64::INFINITY);
+    assert_eq!(DMatrix::<f64>::zeros(0, 0).condition_number(), f64::INFINITY);
+    assert_eq!(Matrix4::<f64>::zero().condition_number(), f64::INFINITY);
+}
+
+#[test]
+fn rank_deficient() {
+    let a: Matrix4<f64> = Matrix4::new([
+        [1.0, 2.0, 3.0, 4.0],
+        [2.0, 4.0, 6.0, 8.0],
+        [0.0, 1.0, 0.0, 1.0],
+        [1.0, 3.0, 3.0, 5.0],
+    ]);
+    let svd = a.svd();
+
+    assert_eq!(a.rank(1e-10), 2);
+    assert!(a.condition_number() > 1e10);
+    check_factors(&svd, &DMatrix::from(a));
+}
+
+#[test]
+fn pseudo_inverse() {
+    let a = sample_matrix();
+    let pinv = a.pseudo_inverse(1e-12);
+
+    assert_eq!(pinv.shape(), [3, 4]);
+    assert_dmatrix_eq(&(&(&a * &pinv).unwrap() * &a).unwrap(), &a, 1e-10);
+    assert_dmatrix_eq(&(&(&pinv * &a).unwrap() * &pinv).unwrap(), &pinv, 1e-10);
+    // With full column rank the pseudo-inverse is a left inverse
+    assert_dmatrix_eq(&(&pinv * &a).unwrap(), &DMatrix::identity(3), 1e-12);
+
+    let singular: Matrix3<f64> = Matrix3::new([
+        [1.0, 0.0, 0.0],
+        [0.0, 2.0, 0.0],
+        [0.0, 0.0, 0.0],
+    ]);
+    let pinv = singular.pseudo_inverse(1e-12);
+    assert_eq!(pinv.values, [[1.0, 0.0, 0.0], [0.0, 0.5, 0.0], [0.0, 0.0, 0.0]]);
+}
+
+#[test]
+fn kabsch() {
+    // Finds the rotation that maps a set of coplanar points onto a rotated copy
+    let rotation: Matrix3<f64> = Matrix3::new([
+        [0.0, -1.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0],
+    ]);
+    let points = [
+        Vector3 { x: 1.0, y: 0.0, z: 0.0 },
+        Vector3 { x: 0.0, y: 2.0, z: 0.0 },
+        Vector3 { x: -1.0, y: -1.0, z: 0.0 },
+        Vector3 { x: 0.0, y: -1.0, z: 0.0 },
+    ];
+    let mut covariance = Matrix3::new([[0.0; 3]; 3]);
+    for point in points.iter() {
+        let rotated = rotation * *point;
+        let (p, q) = ([point.x, point.y, point.z], [rotated.x, rotated.y, rotated.z]);
+        for (row, p) in covariance.values.iter_mut().zip(p.iter()) {
+            for (element, q) in row.iter_mut().zip(q.iter()) {
+                *element += p * q;
+            }
+        }
+    }
+
+    let svd = covariance.svd();
+    let u = Matrix3::try_from(svd.u().clone()).unwrap();
+    let v = Matrix3::try_from(svd.v_t().transpose()).unwrap();
+    let mut found = v * u.transpose();
+    if found.determinant() < 0.0 {
+        let flip = Matrix3::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]]);
+        found = v * flip * u.transpose();
+    }
+
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((found.values[i][j] - rotation.values[i][j]).abs() < 1e-12, "{:?}", found);
+        }
+    }
+}
+
+#[test]
+fn nan_does_not_panic() {
+    let a: DMatrix<f64> = DMatrix::from_row_major(2, 2, vec![
+        f64::NAN, 1.0,
+        2.0, 3.0,
+    ]).unwrap();
+    let svd = Svd::new(&a);
+
+    assert!(svd.singular_values().values.iter().any(|value| value.is_nan()));
+}
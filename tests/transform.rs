@@ -0,0 +1,124 @@
+use linear_algebra::angles::Deg;
+use linear_algebra::matrix::{Matrix3, Matrix4};
+use linear_algebra::point::Point3;
+use linear_algebra::quaternion::Quaternion;
+use linear_algebra::transform::{Affine3, Isometry3, Similarity3, Transform2D};
+use linear_algebra::vector::{Unit, Vector2, Vector3};
+
+mod common;
+
+use common::assert_matrix_eq;
+
+fn assert_point_eq(a: Point3<f64>, b: Point3<f64>, epsilon: f64) {
+    assert!(a.distance(b) <= epsilon, "{:?} != {:?}", a, b);
+}
+
+fn axis() -> Unit<Vector3<f64>> {
+    Unit::new_normalize(Vector3::new(1.0, -2.0, 0.5))
+}
+
+#[test]
+fn isometry_matches_matrix() {
+    let isometry = Isometry3::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Isometry3::from_rotation(Deg(40.0), axis());
+    let matrix = Matrix4::identity().translate(Vector3::new(1.0, 2.0, 3.0)).rotate(Deg(40.0), axis());
+    let point = Point3::new(-1.0, 0.5, 2.0);
+
+    assert_matrix_eq(isometry.to_matrix4(), matrix, 1e-12);
+    assert_point_eq(isometry * point, matrix.transform_point(point).unwrap(), 1e-12);
+    assert!((isometry.transform_vector(Vector3::new(0.0, 1.0, 0.0)) - matrix.transform_vector(Vector3::new(0.0, 1.0, 0.0))).magnitude() < 1e-12);
+
+    let converted = Isometry3::from_matrix4(matrix).unwrap();
+    assert_matrix_eq(Matrix4::from(converted), matrix, 1e-12);
+    assert!(Isometry3::from_matrix4(matrix.scale(Vector3::splat(2.0))).is_none());
+}
+
+#[test]
+fn isometry_inverse_and_interpolation() {
+    let isometry = Isometry3::new(Vector3::new(4.0, -1.0, 2.0), Quaternion::from_axis_angle(axis(), Deg(120.0)));
+    let point = Point3::new(1.0, 2.0, 3.0);
+
+    assert_point_eq(isometry.inverse() * (isometry * point), point, 1e-12);
+    assert_matrix_eq((isometry * isometry.inverse()).to_matrix4(), Matrix4::identity(), 1e-12);
+
+    let start = Isometry3::identity();
+    let halfway = start.interpolate(isometry, 0.5);
+    assert!((halfway.translation - Vector3::new(2.0, -0.5, 1.0)).magnitude() < 1e-12);
+    assert!((halfway.rotation.dot(Quaternion::from_axis_angle(axis(), Deg(60.0))) - 1.0).abs() < 1e-12);
+    assert_eq!(start.interpolate(isometry, 1.0).translation, isometry.translation);
+}
+
+#[test]
+fn similarity() {
+    let similarity = Similarity3::new(Vector3::new(0.0, 1.0, 0.0), Quaternion::from_axis_angle(axis(), Deg(75.0)), 3.0);
+    let matrix = similarity.to_matrix4();
+    let point = Point3::new(2.0, 0.0, -1.0);
+
+    assert_point_eq(similarity * point, matrix.transform_point(point).unwrap(), 1e-12);
+    assert_point_eq(similarity.try_inverse().unwrap() * (similarity * point), point, 1e-12);
+
+    let other = Similarity3::from_scale(0.5) * Similarity3::from(Isometry3::from_translation(Vector3::new(1.0, 1.0, 1.0)));
+    assert_matrix_eq((similarity * other).to_matrix4(), matrix * other.to_matrix4(), 1e-12);
+    assert_eq!(similarity.interpolate(other, 0.5).scale, 1.75);
+
+    let converted = Similarity3::from_matrix4(matrix).unwrap();
+    assert!((converted.scale - 3.0).abs() < 1e-12);
+    assert!(Similarity3::from_matrix4(Matrix4::scaling_matrix(Vector3::new(1.0, 2.0, 1.0))).is_none());
+    assert!(Similarity3::from_scale(0.0).try_inverse().is_none());
+}
+
+#[test]
+fn affine() {
+    let matrix = Matrix4::identity()
+        .translate(Vector3::new(1.0, 2.0, 3.0))
+        .rotate(Deg(20.0), axis())
+        .scale(Vector3::new(2.0, -1.0, 0.5));
+    let affine = Affine3::from_matrix4(matrix).unwrap();
+    let point = Point3::new(0.5, 1.5, -2.0);
+
+    assert_eq!(affine.to_matrix4(), matrix);
+    assert_point_eq(affine * point, matrix.transform_point(point).unwrap(), 1e-12);
+    assert_point_eq(affine.try_inverse().unwrap() * (affine * point), point, 1e-12);
+    assert_matrix_eq((affine * affine).to_matrix4(), matrix * matrix, 1e-12);
+
+    let scale = Affine3::from_scale(Vector3::new(2.0, 2.0, 2.0));
+    let halfway = Affine3::identity().interpolate(scale, 0.5).unwrap();
+    assert_matrix_eq(halfway.to_matrix4(), Matrix4::scaling_matrix(Vector3::splat(1.5)), 1e-12);
+
+    let singular = Affine3::from_parts(Matrix3::from_diagonal(Vector3::new(1.0, 0.0, 1.0)), Vector3::zero());
+    assert!(singular.try_inverse().is_none());
+    assert!(Affine3::from_matrix4(Matrix4::splat(1.0)).is_none());
+}
+
+#[test]
+fn conversions() {
+    let isometry = Isometry3::new(Vector3::new(1.0, 0.0, -1.0), Quaternion::from_axis_angle(axis(), Deg(10.0)));
+    let similarity = Similarity3::from(isometry);
+    let affine = Affine3::from(similarity);
+
+    assert_matrix_eq(affine.to_matrix4(), isometry.to_matrix4(), 1e-12);
+    assert_matrix_eq(Affine3::from(isometry).to_matrix4(), similarity.to_matrix4(), 1e-12);
+    assert_eq!(similarity.isometry(), isometry);
+}
+
+#[test]
+fn transform_2d() {
+    let transform: Transform2D<f64> = Transform2D::identity()
+        .translate(Vector2::new(10.0, 0.0))
+        .rotate(Deg(90.0))
+        .scale(Vector2::new(2.0, 2.0));
+    let point = transform.transform_point(Vector2::new(1.0, 0.0));
+
+    assert!((point - Vector2::new(10.0, 2.0)).magnitude() < 1e-12);
+    assert!((transform.transform_vector(Vector2::new(1.0, 0.0)) - Vector2::new(0.0, 2.0)).magnitude() < 1e-12);
+    assert_eq!(transform.translation(), Vector2::new(10.0, 0.0));
+
+    let sheared = transform.shear(Vector2::new(1.0, 0.0));
+    let inverse = sheared.try_inverse().unwrap();
+    let back = inverse.transform_point(sheared.transform_point(Vector2::new(3.0, -4.0)));
+    assert!((back - Vector2::new(3.0, -4.0)).magnitude() < 1e-12);
+    assert_eq!(Matrix3::from(transform * Transform2D::identity()), transform.matrix);
+
+    assert_eq!(Transform2D::from_matrix3(Matrix3::rotation_2d(Deg(45.0))).unwrap().matrix, Matrix3::rotation_2d(Deg(45.0)));
+    assert!(Transform2D::from_matrix3(Matrix3::<f64>::splat(1.0)).is_none());
+    assert!(Transform2D::identity().scale(Vector2::new(0.0, 1.0)).try_inverse().is_none());
+}
@@ -0,0 +1,283 @@
+use linear_algebra::vector::{DVector, Unit, Vector1, Vector2, Vector3, Vector4, VectorN};
+use linear_algebra::vector::{FromVector3, ToVector2, ToVector4};
+
+use num_traits::{Float, Zero};
+use std::ops::Add;
+
+fn check_magnitude_and_normalize<T: Float + std::fmt::Debug>(epsilon: T) {
+    let from = |value: f64| T::from(value).unwrap();
+
+    assert_eq!(Vector1::new(from(-2.0)).magnitude(), from(2.0));
+    assert_eq!(Vector2::new(from(3.0), from(4.0)).magnitude(), from(5.0));
+    assert_eq!(Vector3::new(from(2.0), from(3.0), from(6.0)).magnitude(), from(7.0));
+    assert_eq!(Vector4::new(from(1.0), from(1.0), from(1.0), from(1.0)).magnitude(), from(2.0));
+
+    let mut vec1 = Vector1::new(from(-3.0));
+    let mut vec2 = Vector2::new(from(3.0), from(4.0));
+    let mut vec3 = Vector3::new(from(2.0), from(3.0), from(6.0));
+    let mut vec4 = Vector4::new(from(1.0), from(-2.0), from(3.0), from(0.5));
+    vec1.normalize();
+    vec2.normalize();
+    vec3.normalize();
+    vec4.normalize();
+
+    assert_eq!(vec1.x, -T::one());
+    assert!((vec2.magnitude() - T::one()).abs() <= epsilon);
+    assert!((vec3.magnitude() - T::one()).abs() <= epsilon);
+    assert!((vec4.magnitude() - T::one()).abs() <= epsilon);
+    assert!((vec2.x - from(0.6)).abs() <= epsilon);
+}
+
+#[test]
+fn magnitude_and_normalize_in_both_precisions() {
+    check_magnitude_and_normalize::<f32>(1e-6);
+    check_magnitude_and_normalize::<f64>(1e-15);
+}
+
+#[test]
+fn display_in_both_precisions() {
+    let expected = "⎛ 1.5 ⎞\n⎜-2.25⎟\n⎜  3  ⎟\n⎝  0  ⎠\n";
+
+    assert_eq!(Vector4::<f32>::new(1.5, -2.25, 3.0, 0.0).to_string(), expected);
+    assert_eq!(Vector4::<f64>::new(1.5, -2.25, 3.0, 0.0).to_string(), expected);
+}
+
+#[test]
+fn constructors() {
+    assert_eq!(Vector3::<i32>::zero(), Vector3::new(0, 0, 0));
+    assert_eq!(Vector4::splat(2.5), Vector4::new(2.5, 2.5, 2.5, 2.5));
+    assert_eq!(Vector2::<u32>::unit_y(), Vector2::new(0, 1));
+    assert_eq!(Vector4::<f64>::unit_w(), Vector4::new(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(VectorN::<i32, 5>::unit(3).values, [0, 0, 0, 1, 0]);
+    assert_eq!(DVector::<f32>::splat(2, 1.0), DVector::new(vec![1.0, 1.0]));
+    assert_eq!(DVector::<i32>::unit(3, 0), DVector::new(vec![1, 0, 0]));
+}
+
+fn sum_all<V: Zero + Add<Output = V> + Copy>(vectors: &[V]) -> V {
+    vectors.iter().fold(V::zero(), |sum, vec| sum + *vec)
+}
+
+#[test]
+fn zero_trait() {
+    let vectors = [Vector3::new(1, 2, 3), Vector3::new(-1, 0, 2)];
+
+    assert_eq!(sum_all(&vectors), Vector3::new(0, 2, 5));
+    assert_eq!(sum_all::<Vector1<f64>>(&[]), Vector1::new(0.0));
+    assert!(Vector2::<f32>::zero().is_zero());
+    assert!(!Vector1::new(1).is_zero());
+}
+
+#[test]
+fn scalar_operators() {
+    let vec = Vector3::new(1.0, -2.0, 4.0);
+
+    assert_eq!(vec * 2.0, Vector3::new(2.0, -4.0, 8.0));
+    assert_eq!(2.0 * vec, vec * 2.0);
+    assert_eq!(vec / 2.0, Vector3::new(0.5, -1.0, 2.0));
+    assert_eq!(-vec, Vector3::new(-1.0, 2.0, -4.0));
+    assert_eq!(3 * Vector2::new(1, 2), Vector2::new(3, 6));
+    assert_eq!(2u8 * Vector1::new(4u8), Vector1::new(8));
+    assert_eq!(Vector4::new(2, 4, 6, 8) / 2, Vector4::new(1, 2, 3, 4));
+    assert_eq!((0.5f32 * VectorN::new([2.0, 4.0])).values, [1.0, 2.0]);
+    assert_eq!((-VectorN::new([1, -1]) / 1).values, [-1, 1]);
+
+    let mut vec = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    vec += Vector4::splat(1.0);
+    vec -= Vector4::unit_x();
+    vec *= 2.0;
+    vec /= 4.0;
+    assert_eq!(vec, Vector4::new(0.5, 1.5, 2.0, 2.5));
+
+    let mut vec = VectorN::new([1, 2, 3]);
+    vec += VectorN::splat(1);
+    vec *= 3;
+    vec -= VectorN::unit(0);
+    vec /= 2;
+    assert_eq!(vec.values, [2, 4, 6]);
+}
+
+#[test]
+fn products() {
+    let a = Vector3::new(1, 2, 3);
+    let b = Vector3::new(4, 5, 6);
+
+    assert_eq!(a.dot(b), 32);
+    assert_eq!(a.cross(b), Vector3::new(-3, 6, -3));
+    assert_eq!(a.cross(b), a % b);
+    assert_eq!(Vector3::<i32>::unit_x().triple_product(Vector3::unit_y(), Vector3::unit_z()), 1);
+    assert_eq!(a.triple_product(b, a + b), 0);
+    assert_eq!(Vector2::new(1, 0).perp_dot(Vector2::new(0, 1)), 1);
+    assert_eq!(Vector2::new(0, 1).perp_dot(Vector2::new(1, 0)), -1);
+    assert_eq!(Vector4::new(1, 2, 3, 4).dot(Vector4::splat(1)), 10);
+    assert_eq!(VectorN::new([1, 2]).dot(VectorN::new([3, 4])), 11);
+    assert_eq!(Vector1::new(3).dot(Vector1::new(-2)), -6);
+}
+
+#[test]
+fn vector_n_operators() {
+    let a = VectorN::<i32, 5>::new([1, 2, 3, 4, 5]);
+    let b = VectorN::new([5, 4, 3, 2, 1]);
+
+    assert_eq!((a + b).values, [6; 5]);
+    assert_eq!((a - b).values, [-4, -2, 0, 2, 4]);
+    assert_eq!((a * b).values, [5, 8, 9, 8, 5]);
+    assert_eq!((a * 2).values, [2, 4, 6, 8, 10]);
+    assert_eq!((2 * a).values, [2, 4, 6, 8, 10]);
+    assert_eq!((b / 2).values, [2, 2, 1, 1, 0]);
+    assert_eq!((-a).values, [-1, -2, -3, -4, -5]);
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    c *= 3;
+    c /= 3;
+    c[0] = 0;
+    assert_eq!(c.values, [0, 4, 3, 2, 1]);
+    assert_eq!(c.into_iter().sum::<i32>(), 10);
+}
+
+#[test]
+fn mixed_size_operators() {
+    let a = Vector3::new(1, 2, 3);
+
+    assert_eq!(a + Vector1::new(10), Vector3::new(11, 2, 3));
+    assert_eq!(a - Vector2::new(1, 1), Vector3::new(0, 1, 3));
+    assert_eq!(a * Vector4::new(2, 3, 4, 5), Vector3::new(2, 6, 12));
+    assert_eq!(Vector2::new(1, 2) * a, Vector2::new(1, 4));
+    assert_eq!(Vector4::splat(1) + a, Vector4::new(2, 3, 4, 1));
+}
+
+#[test]
+fn conversions_and_indexing() {
+    let mut a = Vector3::new(1, 2, 3);
+    a[2] = 4;
+
+    assert_eq!(a[0] + a[1] + a[2], 7);
+    assert_eq!(a.to_vec_2(), Vector2::new(1, 2));
+    assert_eq!(a.to_vec_4(), Vector4::new(1, 2, 4, 0));
+    assert_eq!(Vector4::from_vec_3(a), Vector4::new(1, 2, 4, 0));
+    assert_eq!(Vector1::from_vec_3(a), Vector1::new(1));
+    assert_eq!(VectorN::from(a).values, [1, 2, 4]);
+    assert_eq!(Vector3::from(VectorN::new([5, 6, 7])), Vector3::new(5, 6, 7));
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let mut a = Vector2::new(1, 2);
+    a[2] = 3;
+}
+
+#[test]
+fn distances_and_interpolation() {
+    let a = Vector2::new(1.0, 1.0);
+    let b = Vector2::new(4.0, 5.0);
+
+    assert_eq!(a.distance_squared(b), 25.0);
+    assert_eq!(a.distance(b), 5.0);
+    assert_eq!(a.lerp(b, 0.5), Vector2::new(2.5, 3.0));
+    assert_eq!(Vector3::new(0, 0, 0).lerp(Vector3::new(2, 4, 6), 2), Vector3::new(4, 8, 12));
+    assert_eq!(VectorN::new([0.0, 3.0]).distance(VectorN::new([4.0, 0.0])), 5.0);
+}
+
+#[test]
+fn angles_and_projections() {
+    let x = Vector3::<f64>::unit_x();
+    let diagonal = Vector3::new(1.0, 1.0, 0.0);
+
+    assert!((x.angle_between(diagonal).unwrap().0 - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    assert!((x.angle_between(-x).unwrap().0 - std::f64::consts::PI).abs() < 1e-12);
+    assert_eq!(x.angle_between(x * 3.0).unwrap().0, 0.0);
+    assert_eq!(x.angle_between(Vector3::zero()), None);
+    assert_eq!(Vector3::zero().angle_between(x), None);
+    assert_eq!(x.angle_between(Vector3::new(f64::NAN, 0.0, 0.0)), None);
+    assert!(VectorN::new([1.0f32, 0.0]).angle_between(VectorN::zero()).is_none());
+    assert_eq!(diagonal.project_onto(x * 2.0), x);
+    assert_eq!(diagonal.reject_from(x), Vector3::unit_y());
+    assert_eq!(Vector4::new(1.0, 2.0, 3.0, 4.0).project_onto(Vector4::unit_w()), Vector4::new(0.0, 0.0, 0.0, 4.0));
+}
+
+#[test]
+fn reflect_and_refract() {
+    let normal = Vector2::<f64>::unit_y();
+    let incoming = Vector2::new(1.0, -1.0) / 2.0f64.sqrt();
+
+    assert_eq!(Vector2::new(1.0, -1.0).reflect(normal), Vector2::new(1.0, 1.0));
+    assert_eq!(incoming.refract(normal, 1.0), Some(incoming));
+
+    // Going from glass to air at 45 degrees exceeds the critical angle
+    assert_eq!(incoming.refract(normal, 1.5), None);
+
+    let refracted = incoming.refract(normal, 1.0 / 1.5).unwrap();
+    assert!((refracted.magnitude() - 1.0).abs() < 1e-12);
+    assert!((refracted.x - incoming.x / 1.5).abs() < 1e-12);
+    assert!(refracted.y < 0.0);
+}
+
+#[test]
+fn lengths() {
+    let vec = Vector3::new(2.0, 3.0, 6.0);
+
+    assert_eq!(vec.magnitude_squared(), 49.0);
+    assert_eq!(Vector2::new(3, 4).magnitude_squared(), 25);
+    assert_eq!(vec.normalized(), Vector3::new(2.0 / 7.0, 3.0 / 7.0, 6.0 / 7.0));
+    assert_eq!(vec.with_length(14.0), Vector3::new(4.0, 6.0, 12.0));
+    assert_eq!(vec.clamp_length(0.0, 3.5), vec.normalized() * 3.5);
+    assert_eq!(vec.clamp_length(10.0, 20.0), vec.with_length(10.0));
+    assert_eq!(vec.clamp_length(1.0, 10.0), vec);
+    assert_eq!(Vector1::new(-4.0).normalized(), Vector1::new(-1.0));
+    assert_eq!(VectorN::new([0.0, 5.0]).normalized().values, [0.0, 1.0]);
+}
+
+#[test]
+fn zero_length() {
+    let zero = Vector4::<f32>::zero();
+
+    assert_eq!(zero.try_normalize(0.0), None);
+    assert_eq!(Vector2::new(1e-9, 0.0).try_normalize(1e-6), None);
+    assert_eq!(Vector2::new(2.0, 0.0).try_normalize(1e-6), Some(Vector2::unit_x()));
+    assert_eq!(zero.normalize_or_zero(), zero);
+    assert_eq!(zero.with_length(2.0), zero);
+    assert_eq!(zero.clamp_length(1.0, 2.0), zero);
+    assert!(zero.normalized().x.is_nan());
+}
+
+#[test]
+fn unit() {
+    let axis = Unit::new_normalize(Vector3::new(0.0, 0.0, 5.0));
+
+    assert_eq!(*axis, Vector3::unit_z());
+    assert_eq!(axis.z, 1.0);
+    assert_eq!((-axis).into_inner(), Vector3::new(0.0, 0.0, -1.0));
+    assert_eq!(Unit::try_new(Vector2::<f64>::zero(), 1e-12), None);
+    assert_eq!(Unit::try_new(VectorN::new([3.0, 4.0]), 1e-12).map(|unit| unit.values), Some([0.6, 0.8]));
+}
+
+#[test]
+fn swizzles() {
+    let vec = Vector4::new(1, 2, 3, 4);
+
+    assert_eq!(vec.xy(), Vector2::new(1, 2));
+    assert_eq!(vec.wzyx(), Vector4::new(4, 3, 2, 1));
+    assert_eq!(vec.xxyy(), Vector4::new(1, 1, 2, 2));
+    assert_eq!(vec.zyx(), Vector3::new(3, 2, 1));
+    assert_eq!(Vector3::new(1, 2, 3).xyzx(), Vector4::new(1, 2, 3, 1));
+    assert_eq!(Vector2::new(1, 2).yxy(), Vector3::new(2, 1, 2));
+
+    let mut vec = Vector3::new(1, 2, 3);
+    vec.set_zx(Vector2::new(5, 6));
+    assert_eq!(vec, Vector3::new(6, 2, 5));
+    vec.set_yzx(Vector3::new(7, 8, 9));
+    assert_eq!(vec, Vector3::new(9, 7, 8));
+
+    let mut vec = Vector4::zero();
+    vec.set_wx(Vector2::new(1.0, 2.0));
+    assert_eq!(vec, Vector4::new(2.0, 0.0, 0.0, 1.0));
+    vec.set_wzyx(Vector4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(vec, Vector4::new(4.0, 3.0, 2.0, 1.0));
+
+    let mut vec = Vector2::new(1, 2);
+    assert_eq!(vec.yyyx(), Vector4::new(2, 2, 2, 1));
+    vec.set_yx(vec);
+    assert_eq!(vec, Vector2::new(2, 1));
+}